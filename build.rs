@@ -0,0 +1,68 @@
+//! Generates the list of solution modules that the `all_days` binary runs in-process.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    // NOTE: solutions are named after their year and day, e.g. `2024-01.rs`.
+    let mut days: Vec<String> = fs::read_dir(Path::new(&manifest_dir).join("src").join("bin"))
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter_map(|name| name.strip_suffix(".rs").map(String::from))
+                .filter(|name| {
+                    name.len() == 7
+                        && name.char_indices().all(|(i, c)| {
+                            if i == 4 {
+                                c == '-'
+                            } else {
+                                c.is_ascii_digit()
+                            }
+                        })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_unstable();
+
+    // NOTE: every solution declares a global allocator when profiling with DHAT, so they cannot be linked
+    // into a single binary. `all_days` is not used in that case and gets an empty registry.
+    if env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some() {
+        days.clear();
+    }
+
+    let mut modules = String::new();
+    let mut registrations = String::new();
+
+    for name in &days {
+        let module = format!("day_{}", name.replace('-', "_"));
+        let path = Path::new(&manifest_dir)
+            .join("src")
+            .join("bin")
+            .join(format!("{name}.rs"));
+
+        modules.push_str(&format!(
+            "#[path = {:?}]\n#[allow(dead_code)]\nmod {module};\n",
+            path.display().to_string()
+        ));
+        registrations.push_str(&format!("    registry.register({module}::SOLUTION);\n"));
+    }
+
+    let generated = format!(
+        "{modules}\n\
+        /// Collects the solutions of all days in `src/bin`.\n\
+        fn registry() -> advent_of_code::template::registry::Registry {{\n\
+        \x20   #[allow(unused_mut)]\n\
+        \x20   let mut registry = advent_of_code::template::registry::Registry::default();\n\
+        {registrations}\
+        \x20   registry\n\
+        }}\n"
+    );
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), generated).unwrap();
+}
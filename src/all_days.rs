@@ -0,0 +1,6 @@
+//! Runs the solutions of all days in a single process, see [`advent_of_code::template::registry`].
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+fn main() {
+    advent_of_code::template::registry::run_main(&registry());
+}
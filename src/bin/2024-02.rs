@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, parse = parse_input);
 
 fn parse_input(input: &str) -> Vec<Vec<isize>> {
     input
@@ -49,20 +49,20 @@ pub fn report_safe_p2(report: &[isize]) -> bool {
         .any(|tolerant_report| report_safe_p1(&tolerant_report))
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &[Vec<isize>]) -> Option<usize> {
     Some(
-        parse_input(input)
-            .into_iter()
-            .map(|report| report_safe_p1(&report) as usize)
+        input
+            .iter()
+            .map(|report| report_safe_p1(report) as usize)
             .sum(),
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(input: &[Vec<isize>]) -> Option<usize> {
     Some(
-        parse_input(input)
-            .into_iter()
-            .map(|report| report_safe_p2(&report) as usize)
+        input
+            .iter()
+            .map(|report| report_safe_p2(report) as usize)
             .sum(),
     )
 }
@@ -74,7 +74,7 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let result = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let result = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(
             result,
             vec![
@@ -112,13 +112,17 @@ mod tests {
 
     #[test]
     fn test_day_2_part_one_from_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_day_2_part_two_from_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(4));
     }
 }
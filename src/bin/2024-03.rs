@@ -56,7 +56,7 @@ mod tests {
 
     #[test]
     fn test_day_3_part_one_from_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(161));
     }
 
@@ -1,8 +1,9 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse_input);
 
 type OrderingRule = (usize, usize);
+type SafetyManual = (Vec<OrderingRule>, Vec<Vec<usize>>);
 
-fn parse_input(input: &str) -> (Vec<OrderingRule>, Vec<Vec<usize>>) {
+fn parse_input(input: &str) -> SafetyManual {
     let lines = input.lines().collect::<Vec<_>>();
     let mut split_lines = lines.split(|line| line.is_empty());
     let rule_lines = split_lines.next().unwrap();
@@ -61,26 +62,22 @@ fn reorder(pages: &[usize], rules: &[OrderingRule]) -> Vec<usize> {
     ordered_pages
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (rules, updates) = parse_input(input);
-
+pub fn part_one((rules, updates): &SafetyManual) -> Option<usize> {
     Some(
         updates
-            .into_iter()
-            .filter(|pages| is_in_order(pages, &rules))
+            .iter()
+            .filter(|pages| is_in_order(pages, rules))
             .map(|pages| pages[pages.len() / 2])
             .sum(),
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (rules, updates) = parse_input(input);
-
+pub fn part_two((rules, updates): &SafetyManual) -> Option<usize> {
     Some(
         updates
-            .into_iter()
-            .filter(|pages| !is_in_order(pages, &rules))
-            .map(|pages| reorder(&pages, &rules))
+            .iter()
+            .filter(|pages| !is_in_order(pages, rules))
+            .map(|pages| reorder(pages, rules))
             .map(|pages| pages[pages.len() / 2])
             .sum(),
     )
@@ -93,7 +90,7 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let result = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let result = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
 
         assert_eq!(
             result,
@@ -135,7 +132,9 @@ mod tests {
 
     #[test]
     fn test_day_5_part_one_from_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(143));
     }
 
@@ -173,7 +172,9 @@ mod tests {
 
     #[test]
     fn test_day_5_part_two_from_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(123));
     }
 }
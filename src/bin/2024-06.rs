@@ -211,7 +211,7 @@ mod tests {
 
     #[test]
     fn test_day_6_part_one_from_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(41));
     }
 
@@ -219,7 +219,7 @@ mod tests {
     #[case(Point(6, 3), true)]
     #[case(Point(5, 4), false)]
     fn test_is_loop(#[case] added_obstacle: Point, #[case] should_loop: bool) {
-        let mut field = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let mut field = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
         field.add_obstacle(added_obstacle);
 
         assert_eq!(field.is_loop(), should_loop);
@@ -227,7 +227,7 @@ mod tests {
 
     #[test]
     fn test_day_6_part_two_from_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6));
     }
 }
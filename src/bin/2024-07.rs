@@ -1,7 +1,7 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, parse = parse_input);
 
 #[derive(Debug, PartialEq)]
-struct Calibration {
+pub struct Calibration {
     goal: usize,
     instructions: Vec<usize>,
 }
@@ -66,20 +66,20 @@ fn parse_input(input: &str) -> Vec<Calibration> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &[Calibration]) -> Option<usize> {
     Some(
-        parse_input(input)
-            .into_iter()
+        input
+            .iter()
             .filter(|calibration| calibration.can_resolve(false))
             .map(|calibration| calibration.goal)
             .sum(),
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(input: &[Calibration]) -> Option<usize> {
     Some(
-        parse_input(input)
-            .into_iter()
+        input
+            .iter()
             .filter(|calibration| calibration.can_resolve(true))
             .map(|calibration| calibration.goal)
             .sum(),
@@ -92,7 +92,7 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let result = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let result = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(
             result,
             vec![
@@ -138,13 +138,17 @@ mod tests {
 
     #[test]
     fn test_day_7_part_one_from_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(3749));
     }
 
     #[test]
     fn test_day_7_part_two_from_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(11387));
     }
 }
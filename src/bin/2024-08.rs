@@ -4,7 +4,7 @@ use std::{
     ops::{Add, Mul, Sub},
 };
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, parse = parse_input);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 struct Point(isize, isize);
@@ -88,7 +88,7 @@ impl Antenna {
 }
 
 #[derive(Debug, PartialEq)]
-struct CityField {
+pub struct CityField {
     antennas: HashSet<Antenna>,
     dimensions: (isize, isize),
 }
@@ -134,12 +134,12 @@ fn parse_input(input: &str) -> CityField {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    Some(parse_input(input).all_first_antinode_locations().len())
+pub fn part_one(city: &CityField) -> Option<usize> {
+    Some(city.all_first_antinode_locations().len())
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    Some(parse_input(input).all_repeating_antinode_locations().len())
+pub fn part_two(city: &CityField) -> Option<usize> {
+    Some(city.all_repeating_antinode_locations().len())
 }
 
 #[cfg(test)]
@@ -148,7 +148,7 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let city = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let city = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
 
         assert_eq!(
             city,
@@ -169,13 +169,17 @@ mod tests {
 
     #[test]
     fn test_day_8_part_one_from_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(14));
     }
 
     #[test]
     fn test_day_8_part_two_from_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(34));
     }
 }
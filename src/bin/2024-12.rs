@@ -185,7 +185,7 @@ mod tests {
     #[test]
     fn test_day_12_part_one_from_example_1() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", PUZZLE, 1,
         ));
         assert_eq!(result, Some(140));
     }
@@ -193,7 +193,7 @@ mod tests {
     #[test]
     fn test_day_12_part_one_from_example_2() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(772));
     }
@@ -201,7 +201,7 @@ mod tests {
     #[test]
     fn test_day_12_part_one_from_example_3() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", PUZZLE, 3,
         ));
         assert_eq!(result, Some(1930));
     }
@@ -209,7 +209,7 @@ mod tests {
     #[test]
     fn test_day_12_part_two_from_example_1() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", PUZZLE, 1,
         ));
         assert_eq!(result, Some(80));
     }
@@ -217,7 +217,7 @@ mod tests {
     #[test]
     fn test_day_12_part_two_from_example_2() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(436));
     }
@@ -225,7 +225,7 @@ mod tests {
     #[test]
     fn test_day_12_part_two_from_example_3() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", PUZZLE, 3,
         ));
         assert_eq!(result, Some(1206));
     }
@@ -233,7 +233,7 @@ mod tests {
     #[test]
     fn test_day_12_part_two_from_example_4() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 4,
+            "examples", PUZZLE, 4,
         ));
         assert_eq!(result, Some(236));
     }
@@ -241,7 +241,7 @@ mod tests {
     #[test]
     fn test_day_12_part_two_from_example_5() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 5,
+            "examples", PUZZLE, 5,
         ));
         assert_eq!(result, Some(368));
     }
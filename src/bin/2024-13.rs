@@ -1,6 +1,6 @@
 use std::ops::{Add, Mul, Sub};
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(13, parse = parse_input);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
 struct Point(isize, isize);
@@ -30,7 +30,7 @@ impl Mul<isize> for Point {
 }
 
 #[derive(Debug, PartialEq)]
-struct ClawMachine {
+pub struct ClawMachine {
     button_a: Point,
     button_b: Point,
 
@@ -117,22 +117,22 @@ fn parse_input(input: &str) -> Vec<ClawMachine> {
     result
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(input: &[ClawMachine]) -> Option<usize> {
     Some(
-        parse_input(input)
-            .into_iter()
+        input
+            .iter()
             .filter_map(|machine| machine.minimum_tokens_solve())
             .sum(),
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(input: &[ClawMachine]) -> Option<usize> {
     Some(
-        parse_input(input)
-            .into_iter()
+        input
+            .iter()
             .map(|machine| ClawMachine {
                 prize: Point(10000000000000, 10000000000000) + machine.prize,
-                ..machine
+                ..*machine
             })
             .filter_map(|machine| machine.minimum_tokens_solve())
             .sum(),
@@ -145,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let result = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let result = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(
             result,
             vec![
@@ -175,13 +175,17 @@ mod tests {
 
     #[test]
     fn test_day_13_part_one_from_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert_eq!(result, Some(480));
     }
 
     #[test]
     fn test_day_13_part_two_from_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", PUZZLE,
+        )));
         assert!(result.is_some());
     }
 }
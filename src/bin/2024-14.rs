@@ -172,8 +172,11 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let robo_room =
-            RoboRoom::from_input(&advent_of_code::template::read_file("examples", DAY), 11, 7);
+        let robo_room = RoboRoom::from_input(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            11,
+            7,
+        );
         assert_eq!(
             robo_room,
             RoboRoom {
@@ -234,9 +237,12 @@ mod tests {
 
     #[test]
     fn test_positions_at_timepoint() {
-        let result =
-            RoboRoom::from_input(&advent_of_code::template::read_file("examples", DAY), 11, 7)
-                .positions_at_timepoint(100);
+        let result = RoboRoom::from_input(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            11,
+            7,
+        )
+        .positions_at_timepoint(100);
         assert_eq!(
             result,
             vec![
@@ -258,9 +264,12 @@ mod tests {
 
     #[test]
     fn test_safety_factor_at_timepoint() {
-        let result =
-            RoboRoom::from_input(&advent_of_code::template::read_file("examples", DAY), 11, 7)
-                .safety_factor_at_timepoint(100);
+        let result = RoboRoom::from_input(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            11,
+            7,
+        )
+        .safety_factor_at_timepoint(100);
         assert_eq!(result, 12);
     }
 }
@@ -1,14 +1,25 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, dashboard, download, export, history, read, scaffold, solve, time, verify,
+};
+use advent_of_code::template::{legacy_data, PuzzleId, Year};
 use args::{parse, AppArguments};
 
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::{
+            solve::SolveOptions,
+            time::{CompareOptions, TimeOptions},
+        },
+        input::InputSource,
+        output::OutputFormat,
+        report::{Column, ReportFormat},
+        runner::BenchConfig,
+        Day, Year,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -17,63 +28,277 @@ mod args {
         Read {
             day: Day,
         },
+        History {
+            day: Day,
+        },
+        Dashboard {
+            output: String,
+            table: Option<String>,
+        },
+        Export {
+            format: Option<ReportFormat>,
+            columns: Vec<Column>,
+            output: Option<String>,
+            table: Option<String>,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
+            expected_part_one: Vec<String>,
+            expected_part_two: Vec<String>,
         },
         Solve {
             day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            options: SolveOptions,
         },
         All {
             release: bool,
+            format: OutputFormat,
+            jobs: usize,
         },
         Time {
-            all: bool,
             day: Option<Day>,
-            store: bool,
+            options: TimeOptions,
+        },
+        Verify {
+            day: Option<Day>,
+            release: bool,
+            jobs: usize,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Reads the benchmark config from the environment, overridden by any flags that are present.
+    fn parse_bench_config(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        let mut config = BenchConfig::from_env()?;
+
+        if let Some(ms) = args.opt_value_from_str("--budget")? {
+            config.budget = Duration::from_millis(ms);
+        }
+        if let Some(ms) = args.opt_value_from_str("--warmup")? {
+            config.warmup = Duration::from_millis(ms);
+        }
+        if let Some(n) = args.opt_value_from_str("--min-samples")? {
+            config.min_samples = n;
+        }
+        if let Some(n) = args.opt_value_from_str("--max-samples")? {
+            config.max_samples = n;
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn parse_format(
+        args: &mut pico_args::Arguments,
+    ) -> Result<OutputFormat, Box<dyn std::error::Error>> {
+        Ok(args.opt_value_from_str("--format")?.unwrap_or_default())
+    }
+
+    /// Number of days to solve concurrently. Defaults to one day at a time.
+    fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--jobs")? {
+            Some(0) => Err("expecting `--jobs` to be at least 1".into()),
+            Some(jobs) => Ok(jobs),
+            None => Ok(1),
+        }
+    }
+
+    /// Comparison is enabled by `--compare` or by naming a `--baseline` file.
+    fn parse_compare_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<CompareOptions>, Box<dyn std::error::Error>> {
+        let compare = args.contains("--compare");
+        let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+        let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+
+        if threshold.is_some_and(|t| !t.is_finite() || t < 0.0) {
+            return Err("expecting `--threshold` to be a non-negative percentage".into());
+        }
+
+        if !compare && baseline.is_none() {
+            return match threshold {
+                Some(_) => Err("`--threshold` requires `--compare` or `--baseline`".into()),
+                None => Ok(None),
+            };
+        }
+
+        Ok(Some(CompareOptions {
+            baseline,
+            threshold_pct: threshold.unwrap_or(CompareOptions::DEFAULT_THRESHOLD_PCT),
+        }))
+    }
+
+    /// Year of the puzzles, from `--year` or `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        args.opt_value_from_str("--year")?
+            .or_else(Year::from_env)
+            .ok_or_else(|| {
+                "no year given. Pass `--year` or set AOC_YEAR, e.g. in .cargo/config.toml.".into()
+            })
+    }
+
+    /// Checks that the selected year has a puzzle on the given day.
+    fn check_day(year: Year, day: Day) -> Result<Day, Box<dyn std::error::Error>> {
+        if year.has_day(day) {
+            Ok(day)
+        } else {
+            Err(format!(
+                "{year} only has puzzles on days 1 to {}",
+                year.last_day().into_inner()
+            )
+            .into())
+        }
+    }
+
+    /// Input of a solution: `--input <path>`, `--input -` for stdin or `--example [N]`.
+    /// Parsed after all other flags, so the optional example number is the last free argument.
+    fn parse_input_source(
+        args: &mut pico_args::Arguments,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let input: Option<String> = args.opt_value_from_str("--input")?;
+        let example = args.contains("--example");
+
+        match (input, example) {
+            (Some(_), true) => Err("`--input` and `--example` cannot be combined".into()),
+            (Some(input), false) if input == "-" => Ok(InputSource::Stdin),
+            (Some(path), false) => Ok(InputSource::Path(path)),
+            (None, true) => match args.opt_free_from_str()? {
+                Some(0) => Err("expecting `--example` to count from 1".into()),
+                n => Ok(InputSource::Example(n)),
+            },
+            (None, false) => Ok(InputSource::Puzzle),
+        }
+    }
+
+    /// Name of a readme benchmarking table. Used in file names and markers, so kept to `[A-Za-z0-9_-]`.
+    fn parse_table(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let table: Option<String> = args.opt_value_from_str("--table")?;
+
+        match table {
+            Some(name)
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+            {
+                Err("expecting `--table` to only contain letters, digits, `-` and `_`".into())
+            }
+            table => Ok(table),
+        }
+    }
+
+    /// Table given by `--table`. Other years get their own benchmarking table unless one is given.
+    fn parse_year_table(
+        args: &mut pico_args::Arguments,
+        year: Year,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        Ok(parse_table(args)?
+            .or_else(|| (Year::from_env() != Some(year)).then(|| year.to_string())))
+    }
+
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = parse_year(&mut args)?;
+        year.last_day_override()?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                format: parse_format(&mut args)?,
+                jobs: parse_jobs(&mut args)?,
             },
             Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
+                let options = TimeOptions {
+                    run_all: args.contains("--all"),
+                    store: args.contains("--store"),
+                    bench: parse_bench_config(&mut args)?,
+                    format: parse_format(&mut args)?,
+                    jobs: parse_jobs(&mut args)?,
+                    compare: parse_compare_options(&mut args)?,
+                    table: parse_year_table(&mut args, year)?,
+                };
 
                 AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| check_day(year, day))
+                        .transpose()?,
+                    options,
+                }
+            }
+            Some("verify") => {
+                let release = args.contains("--release");
+                let jobs = parse_jobs(&mut args)?;
+
+                AppArguments::Verify {
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| check_day(year, day))
+                        .transpose()?,
+                    release,
+                    jobs,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                day: check_day(year, args.free_from_str()?)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                day: check_day(year, args.free_from_str()?)?,
+            },
+            Some("history") => AppArguments::History {
+                day: check_day(year, args.free_from_str()?)?,
+            },
+            Some("dashboard") => AppArguments::Dashboard {
+                output: args
+                    .opt_value_from_str("--output")?
+                    .unwrap_or_else(|| year.data_path("dashboard.html")),
+                table: parse_year_table(&mut args, year)?,
+            },
+            Some("export") => AppArguments::Export {
+                format: args.opt_value_from_str("--format")?,
+                columns: args
+                    .opt_value_from_fn("--columns", Column::parse_list)?
+                    .unwrap_or_else(|| Column::DEFAULT.to_vec()),
+                output: args.opt_value_from_str("--output")?,
+                table: parse_year_table(&mut args, year)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                day: check_day(year, args.free_from_str()?)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
+                expected_part_one: args.values_from_str("--expected-1")?,
+                expected_part_two: args.values_from_str("--expected-2")?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let day = check_day(year, args.free_from_str()?)?;
+                let options = SolveOptions {
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    force: args.contains("--force"),
+                    dhat: args.contains("--dhat"),
+                    time: args.contains("--time"),
+                    bench: parse_bench_config(&mut args)?,
+                    format: parse_format(&mut args)?,
+                    input: parse_input_source(&mut args)?,
+                };
+
+                if options.submit.is_some() && options.input != InputSource::Puzzle {
+                    return Err("`--submit` only works with the puzzle input".into());
+                }
+
+                AppArguments::Solve { day, options }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -91,49 +316,85 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
     }
 }
 
 fn main() {
+    if let Some(year) = Year::from_env() {
+        match legacy_data::migrate(year) {
+            Ok(moved) => {
+                for path in moved {
+                    println!("Moved legacy data file to {path:?}.");
+                }
+            }
+            Err(e) => eprintln!("Failed to move legacy data files to \"data/{year}\": {e}"),
+        }
+    }
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                release,
+                format,
+                jobs,
+            } => all::handle(year, release, format, jobs),
+            AppArguments::Time { day, options } => time::handle(year, day, &options),
+            AppArguments::Verify { day, release, jobs } => {
+                verify::handle(year, day, release, jobs);
+            }
+            AppArguments::Download { day } => {
+                download::handle(PuzzleId::new(year, day));
+            }
+            AppArguments::Read { day } => read::handle(PuzzleId::new(year, day)),
+            AppArguments::History { day } => history::handle(PuzzleId::new(year, day)),
+            AppArguments::Dashboard { output, table } => {
+                dashboard::handle(year, table.as_deref(), &output);
+            }
+            AppArguments::Export {
+                format,
+                columns,
+                output,
+                table,
+            } => export::handle(year, table.as_deref(), format, &columns, output.as_deref()),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
+                expected_part_one,
+                expected_part_two,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
+                let id = PuzzleId::new(year, day);
+                // NOTE: download first, so the scaffolded tests can use the expected example answers.
+                let puzzle = download.then(|| download::handle(id));
+                scaffold::handle(
+                    id,
+                    overwrite,
+                    template.as_deref(),
+                    puzzle.as_ref(),
+                    [&expected_part_one, &expected_part_two],
+                );
+            }
+            AppArguments::Solve { day, options } => {
+                solve::handle(PuzzleId::new(year, day), &options);
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match PuzzleId::today() {
+                    Some(id) => {
+                        let puzzle = download::handle(id);
+                        scaffold::handle(id, false, None, Some(&puzzle), [&[], &[]]);
+                        read::handle(id)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run on days of december with a puzzle. \
+                            Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,292 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Part, Year};
+
+/// Name of the answers file in the data directory of a year.
+static ANSWERS_FILE_NAME: &str = "answers.json";
+
+/// Represents the accepted answers for a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+/// Result of comparing a solution's answer with the accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verification {
+    /// The answer matches the accepted answer.
+    Pass,
+    /// The answer differs from the accepted answer, or the solution did not produce one.
+    Fail { expected: String },
+    /// There is no accepted answer to compare with.
+    Missing,
+}
+
+impl Answers {
+    /// Dehydrate answers to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(year.data_path(ANSWERS_FILE_NAME))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from the JSON file of a year. If not present, returns empty answers.
+    /// A file that cannot be read or parsed is an error, so it is never mistaken for having no answers.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = year.data_path(ANSWERS_FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                Answers::try_from(content).map_err(|e| format!("could not parse \"{path}\": {e}"))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read \"{path}\": {e}")),
+        }
+    }
+
+    /// The accepted answer for a part, if known.
+    pub fn get(&self, day: Day, part: Part) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            Part::One => answer.part_1.as_deref(),
+            Part::Two => answer.part_2.as_deref(),
+        }
+    }
+
+    /// Records the accepted answer for a part, replacing a previous one.
+    pub fn set(&mut self, day: Day, part: Part, value: &str) {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        let answer = &mut self.data[index];
+        match part {
+            Part::One => answer.part_1 = Some(value.into()),
+            Part::Two => answer.part_2 = Some(value.into()),
+        }
+    }
+
+    /// Compares the answer a solution produced for a part with the accepted answer.
+    pub fn verify(&self, day: Day, part: Part, answer: Option<&str>) -> Verification {
+        match self.get(day, part) {
+            None => Verification::Missing,
+            Some(expected) if answer == Some(expected) => Verification::Pass,
+            Some(expected) => Verification::Fail {
+                expected: expected.into(),
+            },
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, Answers, Verification};
+    use crate::{day, template::Part, year};
+    use tinyjson::JsonValue;
+
+    fn get_mock_answers() -> Answers {
+        Answers {
+            data: vec![
+                Answer {
+                    day: day!(1),
+                    part_1: Some("11".into()),
+                    part_2: Some("31".into()),
+                },
+                Answer {
+                    day: day!(3),
+                    part_1: Some("161".into()),
+                    part_2: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "11", "part_2": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), Part::One), Some("11"));
+        assert_eq!(answers.get(day!(1), Part::Two), None);
+        assert_eq!(answers.get(day!(2), Part::One), None);
+    }
+
+    #[test]
+    fn reads_missing_file_as_empty() {
+        assert_eq!(Answers::read_from_file(year!(2015)), Ok(Answers::default()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": 11 }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = get_mock_answers();
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn sets_answers() {
+        let mut answers = get_mock_answers();
+        answers.set(day!(3), Part::Two, "48");
+        answers.set(day!(2), Part::One, "2");
+        answers.set(day!(1), Part::One, "12");
+
+        assert_eq!(answers.data.len(), 3);
+        assert_eq!(answers.data[1].day, day!(2));
+        assert_eq!(answers.get(day!(1), Part::One), Some("12"));
+        assert_eq!(answers.get(day!(2), Part::One), Some("2"));
+        assert_eq!(answers.get(day!(2), Part::Two), None);
+        assert_eq!(answers.get(day!(3), Part::Two), Some("48"));
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.verify(day!(1), Part::One, Some("11")),
+            Verification::Pass
+        );
+        assert_eq!(
+            answers.verify(day!(1), Part::Two, Some("30")),
+            Verification::Fail {
+                expected: "31".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(day!(3), Part::One, None),
+            Verification::Fail {
+                expected: "161".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(day!(3), Part::Two, Some("48")),
+            Verification::Missing
+        );
+    }
+}
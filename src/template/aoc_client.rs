@@ -0,0 +1,435 @@
+//! Native client for the Advent of Code website.
+//! Downloads inputs and puzzle descriptions and submits answers, authenticated by a session cookie.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::puzzle::{extract_elements, strip_tags, Puzzle};
+use crate::template::{Day, Part, Year};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const SESSION_ENV: &str = "AOC_SESSION";
+const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Name of the file holding the session cookie, compatible with aoc-cli.
+const SESSION_FILE_NAME: &str = ".adventofcode.session";
+
+/// See <https://www.reddit.com/r/adventofcode/wiki/faqs/automation>.
+const USER_AGENT: &str = concat!(
+    "github.com/fspoettel/advent-of-code-rust (",
+    env!("CARGO_PKG_NAME"),
+    " ",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    /// The session cookie was rejected, most likely because it expired.
+    Unauthorized,
+    /// The puzzle does not exist or has not been unlocked yet.
+    NotFound,
+    BadStatus(u16),
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or create the file \"~/{SESSION_FILE_NAME}\"."
+            ),
+            AocClientError::Unauthorized => write!(
+                f,
+                "the session cookie was rejected. It might have expired, try refreshing it."
+            ),
+            AocClientError::NotFound => {
+                write!(f, "the puzzle does not exist or has not been unlocked yet.")
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "unexpected response status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(400 | 401 | 403, _) => AocClientError::Unauthorized,
+            ureq::Error::Status(404, _) => AocClientError::NotFound,
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    pub fn new(session: &str, year: Year) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(TIMEOUT)
+                .build(),
+            base_url: DEFAULT_BASE_URL.into(),
+            session: session.into(),
+            year,
+        }
+    }
+
+    /// Sends requests to another server, e.g. a local stand-in for tests.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').into();
+        self
+    }
+
+    /// Configures a client for a year from `AOC_SESSION` (or the session file) and `AOC_BASE_URL`.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = read_session()?;
+        let client = AocClient::new(&session, year);
+
+        Ok(match env::var(BASE_URL_ENV) {
+            Ok(base_url) if !base_url.is_empty() => client.with_base_url(&base_url),
+            _ => client,
+        })
+    }
+
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self.authenticated(self.agent.get(&url)).call()?;
+        Ok(response.into_string()?)
+    }
+
+    pub fn puzzle(&self, day: Day) -> Result<Puzzle, AocClientError> {
+        let response = self
+            .authenticated(self.agent.get(&self.day_url(day)))
+            .call()?;
+        Ok(Puzzle {
+            html: response.into_string()?,
+        })
+    }
+
+    /// Submits an answer and returns how the server judged it.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: Part,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, AocClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let response = self
+            .authenticated(self.agent.post(&url))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        let html = response.into_string()?;
+        let message = extract_elements(&html, "article")
+            .first()
+            .map_or_else(|| strip_tags(&html), |article| strip_tags(article));
+
+        Ok(SubmissionOutcome::from_message(&message))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn authenticated(&self, request: ureq::Request) -> ureq::Request {
+        request.set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Wrong,
+    /// The answer was not checked because the previous submission was too recent.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved, or is not unlocked yet.
+    AlreadySolved,
+    /// The response did not match any known message.
+    Unknown(String),
+}
+
+impl SubmissionOutcome {
+    pub fn from_message(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                SubmissionOutcome::TooHigh
+            } else if message.contains("too low") {
+                SubmissionOutcome::TooLow
+            } else {
+                SubmissionOutcome::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            SubmissionOutcome::RateLimited {
+                wait: parse_wait(message),
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            SubmissionOutcome::AlreadySolved
+        } else {
+            SubmissionOutcome::Unknown(message.into())
+        }
+    }
+
+    /// Whether the server checked the answer and rejected it.
+    pub fn is_rejected(&self) -> bool {
+        matches!(
+            self,
+            SubmissionOutcome::TooHigh | SubmissionOutcome::TooLow | SubmissionOutcome::Wrong
+        )
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "correct"),
+            SubmissionOutcome::TooHigh => write!(f, "too high"),
+            SubmissionOutcome::TooLow => write!(f, "too low"),
+            SubmissionOutcome::Wrong => write!(f, "wrong"),
+            SubmissionOutcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate-limited, wait {}", format_wait(*wait))
+            }
+            SubmissionOutcome::RateLimited { wait: None } => write!(f, "rate-limited"),
+            SubmissionOutcome::AlreadySolved => write!(f, "already solved"),
+            SubmissionOutcome::Unknown(message) => write!(f, "unknown response: {message}"),
+        }
+    }
+}
+
+/// Parses the wait time of a rate-limited submission, e.g. `You have 1m 12s left to wait.`
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    message[start..end]
+        .split_whitespace()
+        .try_fold(0, |secs, part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(secs + value * 3600),
+                "m" => Some(secs + value * 60),
+                "s" => Some(secs + value),
+                _ => None,
+            }
+        })
+        .map(Duration::from_secs)
+}
+
+/// Formats a wait time like the server does, e.g. `1m 12s` or `1h 15m`.
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+    let parts: Vec<String> = [(secs / 3600, "h"), (secs / 60 % 60, "m"), (secs % 60, "s")]
+        .into_iter()
+        .filter(|&(value, _)| value > 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect();
+
+    if parts.is_empty() {
+        "0s".into()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Reads the session cookie from `AOC_SESSION`, or from the session file in the home or config directory.
+fn read_session() -> Result<String, AocClientError> {
+    if let Some(session) = env::var(SESSION_ENV).ok().and_then(|s| parse_session(&s)) {
+        return Ok(session);
+    }
+
+    session_file_candidates()
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .find_map(|content| parse_session(&content))
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+fn session_file_candidates() -> Vec<PathBuf> {
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok();
+    let config = env::var("XDG_CONFIG_HOME")
+        .ok()
+        .or_else(|| home.as_ref().map(|h| format!("{h}/.config")));
+
+    [home, config]
+        .into_iter()
+        .flatten()
+        .map(|dir| Path::new(&dir).join(SESSION_FILE_NAME))
+        .collect()
+}
+
+/// Accepts the bare cookie value as well as `session=<value>`.
+fn parse_session(content: &str) -> Option<String> {
+    let session = content.trim();
+    let session = session.strip_prefix("session=").unwrap_or(session);
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_wait, parse_session, AocClient, AocClientError, SubmissionOutcome};
+    use crate::{day, template::Part, year};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    /// Serves a single canned response on a local port. Joining the handle returns the raw request.
+    fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            reader.into_inner().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn client(base_url: &str) -> AocClient {
+        AocClient::new("abc123", year!(2024)).with_base_url(base_url)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, server) = serve_once("200 OK", "3   4\n4   3\n");
+        let input = client(&base_url).input(day!(1)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "3   4\n4   3\n");
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn maps_error_statuses() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        let result = client(&base_url).puzzle(day!(25));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::NotFound)));
+
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+        let result = client(&base_url).input(day!(1));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::Unauthorized)));
+
+        let (base_url, server) = serve_once("500 Internal Server Error", "");
+        let result = client(&base_url).input(day!(1));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::BadStatus(500))));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<html><main>\n<article><p>That's the right answer!  You are <em>one gold star</em> closer. <a href=\"/2024/day/3#part2\">[Continue to Part Two]</a></p></article>\n</main></html>",
+        );
+        let outcome = client(&base_url)
+            .submit(day!(3), Part::Two, "1234")
+            .unwrap();
+        let request = server.join().unwrap();
+
+        assert!(request.starts_with("POST /2024/day/3/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=1234"));
+        assert_eq!(outcome, SubmissionOutcome::Correct);
+    }
+
+    #[test]
+    fn parses_submission_outcomes() {
+        let cases = [
+            ("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.", SubmissionOutcome::TooHigh),
+            ("That's not the right answer; your answer is too low.", SubmissionOutcome::TooLow),
+            ("That's not the right answer.  If you're stuck, make sure you're using the full input data.", SubmissionOutcome::Wrong),
+            ("You don't seem to be solving the right level.  Did you already complete it? [Return to Day 1]", SubmissionOutcome::AlreadySolved),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 12s left to wait. [Return to Day 1]", SubmissionOutcome::RateLimited { wait: Some(Duration::from_secs(72)) }),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.", SubmissionOutcome::RateLimited { wait: Some(Duration::from_secs(37)) }),
+            ("You gave an answer too recently.", SubmissionOutcome::RateLimited { wait: None }),
+            ("Something else", SubmissionOutcome::Unknown("Something else".into())),
+        ];
+
+        for (message, expected) in cases {
+            assert_eq!(SubmissionOutcome::from_message(message), expected);
+        }
+
+        assert_eq!(format_wait(Duration::from_secs(72)), "1m 12s");
+        assert_eq!(format_wait(Duration::from_secs(60)), "1m");
+        assert_eq!(format_wait(Duration::from_secs(5)), "5s");
+        assert_eq!(format_wait(Duration::from_secs(4500)), "1h 15m");
+        assert_eq!(format_wait(Duration::from_secs(3601)), "1h 1s");
+        assert_eq!(format_wait(Duration::ZERO), "0s");
+    }
+
+    #[test]
+    fn parses_session() {
+        assert_eq!(parse_session("abc\n"), Some("abc".into()));
+        assert_eq!(parse_session("session=abc"), Some("abc".into()));
+        assert_eq!(parse_session("  \n"), None);
+    }
+}
@@ -0,0 +1,264 @@
+//! Renders stored timings as a self-contained SVG bar chart.
+//! Bars use a logarithmic scale, as durations of different days often differ by orders of magnitude.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::template::output::Stage;
+use crate::template::timings::Timings;
+use crate::template::Part;
+
+const BAR_WIDTH: f64 = 10.0;
+const GROUP_WIDTH: f64 = 3.0 * BAR_WIDTH + 12.0;
+const MARGIN_LEFT: f64 = 64.0;
+const MARGIN_RIGHT: f64 = 16.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 32.0;
+const PLOT_HEIGHT: f64 = 240.0;
+const LEGEND_WIDTH: f64 = 64.0;
+
+const STAGES: [(Stage, &str); 3] = [
+    (Stage::Parse, "#9e9e9e"),
+    (Stage::Part(Part::One), "#4e79a7"),
+    (Stage::Part(Part::Two), "#f28e2b"),
+];
+const TOTAL_COLOR: &str = "#e15759";
+
+/// Range of the y-axis in powers of ten of nanoseconds.
+struct Scale {
+    min_exp: i32,
+    max_exp: i32,
+}
+
+impl Scale {
+    fn new(values: impl Iterator<Item = f64>) -> Self {
+        let (min, max) = values
+            .filter(|&n| n > 0.0)
+            .fold((f64::INFINITY, 0_f64), |(min, max), n| {
+                (min.min(n), max.max(n))
+            });
+
+        if max == 0.0 {
+            return Self {
+                min_exp: 0,
+                max_exp: 3,
+            };
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let (min_exp, max_exp) = (min.log10().floor() as i32, max.log10().ceil() as i32);
+
+        Self {
+            min_exp,
+            max_exp: max_exp.max(min_exp + 1),
+        }
+    }
+
+    /// Height of a bar for `nanos`, in pixels.
+    fn height(&self, nanos: f64) -> f64 {
+        let value = nanos.max(1.0).log10();
+        let range = f64::from(self.max_exp - self.min_exp);
+        ((value - f64::from(self.min_exp)) / range * PLOT_HEIGHT).clamp(0.0, PLOT_HEIGHT)
+    }
+}
+
+/// Renders a chart with one group of bars per day and a line connecting the totals of each day.
+pub fn render_svg(timings: &Timings) -> String {
+    let scale = Scale::new(timings.data.iter().flat_map(|t| {
+        STAGES
+            .iter()
+            .filter_map(|(stage, _)| t.stage(*stage).map(|s| s.nanos))
+            .chain([t.total_nanos])
+    }));
+
+    // NOTE: leave room for the legend when there are only a few days.
+    #[allow(clippy::cast_precision_loss)]
+    let width = MARGIN_LEFT
+        + (GROUP_WIDTH * timings.data.len() as f64).max(LEGEND_WIDTH * 4.0)
+        + MARGIN_RIGHT;
+    let height = MARGIN_TOP + PLOT_HEIGHT + MARGIN_BOTTOM;
+    let bottom = MARGIN_TOP + PLOT_HEIGHT;
+
+    let mut svg = String::new();
+
+    // NOTE: writing to a `String` does not fail.
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="{width}" height="{height}" fill="white"/>"#
+    );
+
+    // y-axis with one grid line per power of ten.
+    for exp in scale.min_exp..=scale.max_exp {
+        let y = bottom - scale.height(10_f64.powi(exp));
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{MARGIN_LEFT}" y1="{y}" x2="{}" y2="{y}" stroke="#e0e0e0"/>"##,
+            width - MARGIN_RIGHT
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="end">{}</text>"#,
+            MARGIN_LEFT - 6.0,
+            y + 4.0,
+            format_power_of_ten(exp)
+        );
+    }
+
+    let mut total_points = vec![];
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    for (i, timing) in timings.data.iter().enumerate() {
+        let group_x = MARGIN_LEFT + GROUP_WIDTH * i as f64 + 6.0;
+
+        for (j, (stage, color)) in STAGES.iter().enumerate() {
+            let Some(stage_timing) = timing.stage(*stage) else {
+                continue;
+            };
+            let x = group_x + BAR_WIDTH * j as f64;
+            let bar_height = scale.height(stage_timing.nanos);
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x}" y="{}" width="{BAR_WIDTH}" height="{bar_height}" fill="{color}"><title>Day {} {stage}: {stage_timing}</title></rect>"#,
+                bottom - bar_height,
+                timing.day,
+            );
+        }
+
+        let center = group_x + 1.5 * BAR_WIDTH;
+        let _ = writeln!(
+            svg,
+            r#"<text x="{center}" y="{}" text-anchor="middle">{}</text>"#,
+            bottom + 16.0,
+            timing.day.into_inner()
+        );
+
+        let total_y = bottom - scale.height(timing.total_nanos);
+        total_points.push(format!("{center},{total_y}"));
+        let _ = writeln!(
+            svg,
+            r#"<circle cx="{center}" cy="{total_y}" r="3" fill="{TOTAL_COLOR}"><title>Day {} Total: {:.1?}</title></circle>"#,
+            timing.day,
+            Duration::from_nanos(timing.total_nanos as u64),
+        );
+    }
+
+    if !total_points.is_empty() {
+        let _ = writeln!(
+            svg,
+            r#"<polyline points="{}" fill="none" stroke="{TOTAL_COLOR}" stroke-width="2"/>"#,
+            total_points.join(" ")
+        );
+    }
+
+    let _ = writeln!(
+        svg,
+        r##"<line x1="{MARGIN_LEFT}" y1="{bottom}" x2="{}" y2="{bottom}" stroke="#616161"/>"##,
+        width - MARGIN_RIGHT
+    );
+
+    // legend
+    let legend = STAGES
+        .iter()
+        .map(|(stage, color)| (stage.to_string(), *color))
+        .chain([("Total".to_string(), TOTAL_COLOR)]);
+
+    for (i, (label, color)) in legend.enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = MARGIN_LEFT + LEGEND_WIDTH * i as f64;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x}" y="14" width="10" height="10" fill="{color}"/><text x="{}" y="23">{label}</text>"#,
+            x + 14.0
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Formats `10^exp` nanoseconds, e.g. `100ns` or `1ms`.
+fn format_power_of_ten(exp: i32) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(10_f64.powi(exp.max(0)) as u64);
+    format!("{duration:?}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_power_of_ten, render_svg, Scale, PLOT_HEIGHT};
+    use crate::{
+        day,
+        template::timings::{StageTiming, Timing, Timings},
+    };
+
+    fn stage(nanos: f64) -> Option<StageTiming> {
+        Some(StageTiming {
+            nanos,
+            samples: 1,
+            stats: None,
+        })
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = Scale::new([50.0, 2e6].into_iter());
+        assert_eq!((scale.min_exp, scale.max_exp), (1, 7));
+        assert!((scale.height(10.0)).abs() < 1e-9);
+        assert!((scale.height(1e4) - PLOT_HEIGHT / 2.0).abs() < 1e-9);
+        assert!((scale.height(1e7) - PLOT_HEIGHT).abs() < 1e-9);
+    }
+
+    #[test]
+    fn formats_axis_labels() {
+        assert_eq!(format_power_of_ten(0), "1ns");
+        assert_eq!(format_power_of_ten(4), "10µs");
+        assert_eq!(format_power_of_ten(9), "1s");
+    }
+
+    #[test]
+    fn renders_bars_and_total() {
+        let timings = Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: stage(1e3),
+                    part_2: stage(1e6),
+                    total_nanos: 1_001_000.0,
+                },
+                Timing {
+                    day: day!(2),
+                    parse: stage(10.0),
+                    part_1: stage(100.0),
+                    part_2: None,
+                    total_nanos: 110.0,
+                },
+            ],
+        };
+
+        let svg = render_svg(&timings);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<title>").count(), 6);
+        assert!(svg.contains("<title>Day 02 Total: 110.0ns</title>"));
+        assert!(svg.contains("<title>Day 01 Part 2: 1.0ms</title>"));
+        assert_eq!(svg.matches("<polyline").count(), 1);
+    }
+
+    #[test]
+    fn renders_empty_timings() {
+        let svg = render_svg(&Timings::default());
+        assert!(svg.contains("</svg>"));
+        assert!(!svg.contains("<polyline"));
+    }
+}
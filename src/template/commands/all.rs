@@ -1,5 +1,5 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{output::OutputFormat, run_multi::run_multi, Year};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Year, is_release: bool, format: OutputFormat, jobs: usize) {
+    run_multi(year, &year.days().collect(), is_release, None, format, jobs);
 }
@@ -0,0 +1,14 @@
+use std::{fs, process};
+
+use crate::template::dashboard::build;
+use crate::template::Year;
+
+pub fn handle(year: Year, table: Option<&str>, output: &str) {
+    match fs::write(output, build(year, table, output)) {
+        Ok(()) => println!("Wrote dashboard to \"{output}\"."),
+        Err(e) => {
+            eprintln!("Failed to write dashboard to \"{output}\": {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,14 +1,54 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::examples::{find_example_files, ExampleFile};
+use crate::template::puzzle::Puzzle;
+use crate::template::{Part, PuzzleId};
+
+/// Downloads input and puzzle, and fills in empty example files. Returns the puzzle.
+pub fn handle(id: PuzzleId) -> Puzzle {
+    download(id).unwrap_or_else(|e| {
+        eprintln!("Failed to download day {} of {}: {e}", id.day, id.year);
         process::exit(1);
+    })
+}
+
+fn download(id: PuzzleId) -> Result<Puzzle, AocClientError> {
+    let client = AocClient::from_env(id.year)?;
+    let day = id.day;
+
+    let input_path = id.data_path("inputs");
+    let puzzle_path = id.puzzle_path();
+
+    for folder in ["inputs", "puzzles", "examples"] {
+        fs::create_dir_all(id.year.data_path(folder))?;
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    fs::write(&input_path, client.input(day)?)?;
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+
+    let puzzle = client.puzzle(day)?;
+    fs::write(&puzzle_path, puzzle.to_markdown())?;
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    let example_files = find_example_files(id);
+    for (i, example) in puzzle.examples().iter().enumerate() {
+        let example_path = ExampleFile::nth_of(i, &example_files).path(id);
+
+        // NOTE: never overwrite examples that were edited by hand.
+        if fs::read_to_string(&example_path).is_ok_and(|content| !content.trim().is_empty()) {
+            continue;
+        }
+
+        fs::write(&example_path, example)?;
+        println!("🎄 Successfully wrote example to \"{}\".", &example_path);
+    }
+
+    for part in Part::ALL {
+        if let Some(answer) = puzzle.expected_answer(part) {
+            println!("🎄 Expected example answer for part {part}: {answer}");
+        }
+    }
+
+    Ok(puzzle)
 }
@@ -0,0 +1,42 @@
+use std::{fs, process};
+
+use crate::template::report::{render, Column, ReportFormat};
+use crate::template::timings::Timings;
+use crate::template::Year;
+
+/// Writes a report of the stored timings of a table to `output`, or prints it if no path is given.
+/// Without an explicit format, it is guessed from the extension of `output`.
+pub fn handle(
+    year: Year,
+    table: Option<&str>,
+    format: Option<ReportFormat>,
+    columns: &[Column],
+    output: Option<&str>,
+) {
+    let timings = Timings::read_from_file(year, table).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    });
+
+    if timings.data.is_empty() {
+        eprintln!("No stored timings. Run `cargo time --store` first.");
+        process::exit(1);
+    }
+
+    let format = format
+        .or_else(|| output.and_then(ReportFormat::from_path))
+        .unwrap_or_default();
+
+    let report = render(&timings, format, columns);
+
+    match output {
+        Some(path) => match fs::write(path, report) {
+            Ok(()) => println!("Wrote report to \"{path}\"."),
+            Err(e) => {
+                eprintln!("Failed to write report to \"{path}\": {e}");
+                process::exit(1);
+            }
+        },
+        None => print!("{report}"),
+    }
+}
@@ -0,0 +1,62 @@
+use crate::template::history::{format_nanos, format_timestamp, History, HistoryEntry};
+use crate::template::{PuzzleId, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(id: PuzzleId) {
+    let PuzzleId { year, day } = id;
+    let history = History::read_from_file(year);
+    let entries = history.for_day(day);
+
+    if entries.is_empty() {
+        println!(
+            "No stored benchmarks for day {day} of {year}. Run `cargo time {day} --year {year} --store` to record one."
+        );
+        return;
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET} ({} runs)", entries.len());
+    println!();
+    print_row(
+        "",
+        "Date",
+        "Commit",
+        "Host",
+        "Profile",
+        ["Parse", "Part 1", "Part 2", "Total"],
+    );
+
+    for entry in &entries {
+        print_entry("", entry);
+    }
+
+    println!();
+
+    if let Some(best) = history.best(day) {
+        print_entry("Best", best);
+    }
+    if let Some(latest) = history.latest(day) {
+        print_entry("Latest", latest);
+    }
+}
+
+fn print_entry(label: &str, entry: &HistoryEntry) {
+    print_row(
+        label,
+        &format_timestamp(entry.run.timestamp),
+        entry.run.commit.as_deref().unwrap_or("-"),
+        entry.run.hostname.as_deref().unwrap_or("-"),
+        &entry.run.profile,
+        [
+            &format_nanos(entry.parse_nanos),
+            &format_nanos(entry.part_1_nanos),
+            &format_nanos(entry.part_2_nanos),
+            &format_nanos(Some(entry.total_nanos())),
+        ],
+    );
+}
+
+fn print_row(label: &str, date: &str, commit: &str, host: &str, profile: &str, times: [&str; 4]) {
+    let [parse, part_1, part_2, total] = times;
+    println!(
+        "{label:<6} {date:<20} {commit:<9} {host:<16} {profile:<8} {parse:>10} {part_1:>10} {part_2:>10} {total:>10}"
+    );
+}
@@ -1,6 +1,10 @@
 pub mod all;
+pub mod dashboard;
 pub mod download;
+pub mod export;
+pub mod history;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod verify;
@@ -1,15 +1,23 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(id: PuzzleId) {
+    if let Err(e) = read(id) {
+        eprintln!("Failed to read day {} of {}: {e}", id.day, id.year);
         process::exit(1);
     }
+}
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+/// Fetches the puzzle, stores its description and prints it.
+fn read(id: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env(id.year)?;
+    let description = client.puzzle(id.day)?.to_markdown();
+
+    fs::create_dir_all(id.year.data_path("puzzles"))?;
+    fs::write(id.puzzle_path(), &description)?;
+    println!("{description}");
+
+    Ok(())
 }
@@ -1,13 +1,13 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::examples::{find_example_files, ExampleFile, Expected};
+use crate::template::puzzle::{puzzle_title, Puzzle};
+use crate::template::templates::{self, TemplateContext};
+use crate::template::{Part, PuzzleId, Year};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +19,107 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates an empty file unless it already exists, e.g. because it was just downloaded.
+fn create_file_if_missing(path: &str) -> Result<bool, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Collects the expected example answers, from the puzzle first so that flags take precedence.
+/// Answers passed as flags apply to the example files in order.
+fn collect_expected(
+    puzzle: Option<&Puzzle>,
+    files: &[ExampleFile],
+    flags: [&[String]; 2],
+) -> Vec<Expected> {
+    let mut expected = vec![];
+
+    if let Some(puzzle) = puzzle {
+        for part in Part::ALL {
+            if let (Some(index), Some(answer)) =
+                (puzzle.example_index(part), puzzle.expected_answer(part))
+            {
+                let file = ExampleFile::nth_of(index, files);
+                if !files.contains(&file) {
+                    eprintln!(
+                        "Ignoring expected answer {answer} for part {part}: there is no file for example {}.",
+                        index + 1
+                    );
+                    continue;
+                }
+
+                expected.push(Expected { file, part, answer });
+            }
+        }
+    }
+
+    for (part, answers) in Part::ALL.into_iter().zip(flags) {
+        if answers.len() > files.len() {
+            eprintln!(
+                "Ignoring {} expected answer(s) for part {part}: there are only {} example file(s).",
+                answers.len() - files.len(),
+                files.len()
+            );
+        }
+
+        for (file, answer) in files.iter().zip(answers) {
+            expected.retain(|e| !(e.file == *file && e.part == part));
+            expected.push(Expected {
+                file: *file,
+                part,
+                answer: answer.clone(),
+            });
+        }
+    }
+
+    expected
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Scaffolds a day from the named template, or the default template if `template` is [`None`].
+/// Tests assert the expected example answers given by `expected` (one list per part) or found in
+/// the downloaded `puzzle`.
+pub fn handle(
+    id: PuzzleId,
+    overwrite: bool,
+    template: Option<&str>,
+    puzzle: Option<&Puzzle>,
+    expected: [&[String]; 2],
+) {
+    let PuzzleId { year, day } = id;
+    let input_path = id.data_path("inputs");
+    let example_path = id.data_path("examples");
+    let module_path = id.bin_path();
+
+    let template = templates::load(template).unwrap_or_else(|e| {
+        eprintln!("Failed to load template: {e}");
+        process::exit(1);
+    });
+
+    for folder in ["inputs", "examples"] {
+        if let Err(e) = fs::create_dir_all(year.data_path(folder)) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
+
+    let mut example_files = find_example_files(id);
+    if example_files.is_empty() {
+        example_files.push(ExampleFile { suffix: None });
+    }
+
+    let context = TemplateContext {
+        day,
+        year,
+        title: puzzle
+            .map(Puzzle::to_markdown)
+            .or_else(|| fs::read_to_string(id.puzzle_path()).ok())
+            .and_then(|markdown| puzzle_title(&markdown)),
+        expected: collect_expected(puzzle, &example_files, expected),
+        example_files,
+    };
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +129,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(templates::render(&template, &context).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,26 +139,38 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
+    match create_file_if_missing(&input_path) {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {}
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    // NOTE: days that already have `NN-K.txt` examples do not need an `NN.txt`.
+    if context
+        .example_files
+        .contains(&ExampleFile { suffix: None })
+    {
+        match create_file_if_missing(&example_path) {
+            Ok(true) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if Year::from_env() == Some(year) {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    }
 }
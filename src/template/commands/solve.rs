@@ -1,9 +1,38 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{
+    input::InputSource, output::OutputFormat, runner::BenchConfig, Part, PuzzleId,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Flags of `cargo solve`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolveOptions {
+    pub release: bool,
+    /// Profile heap allocations with dhat.
+    pub dhat: bool,
+    /// Part to submit the answer of.
+    pub submit: Option<Part>,
+    /// Submit even if the answer looks suspicious.
+    pub force: bool,
+    pub time: bool,
+    pub bench: BenchConfig,
+    pub format: OutputFormat,
+    pub input: InputSource,
+}
+
+pub fn handle(id: PuzzleId, options: &SolveOptions) {
+    let SolveOptions {
+        release,
+        dhat,
+        submit: submit_part,
+        force,
+        time,
+        ref bench,
+        format,
+        ref input,
+    } = *options;
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), id.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -21,10 +50,24 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if force {
+            cmd_args.push("--force".to_string());
+        }
     }
 
+    if time {
+        cmd_args.push("--time".to_string());
+    }
+
+    cmd_args.push("--format".to_string());
+    cmd_args.push(format.to_string());
+
+    cmd_args.extend(input.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
+        .envs(bench.to_env())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -1,35 +1,131 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process};
 
+use crate::template::compare::{compare, print_deltas};
+use crate::template::history::{History, HistoryEntry, RunInfo};
+use crate::template::output::OutputFormat;
 use crate::template::run_multi::run_multi;
+use crate::template::runner::BenchConfig;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{readme_benchmarks, Day, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Compares new timings with a baseline when passing `--compare`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompareOptions {
+    /// Timings file to compare with. Defaults to the stored timings of the selected table.
+    pub baseline: Option<String>,
+    /// Relative slowdown, in percent, above which a stage counts as a regression.
+    pub threshold_pct: f64,
+}
+
+impl CompareOptions {
+    pub const DEFAULT_THRESHOLD_PCT: f64 = 10.0;
+}
+
+/// Flags of `cargo time`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeOptions {
+    /// Also run days that are already fully benched.
+    pub run_all: bool,
+    /// Store the timings and update the readme.
+    pub store: bool,
+    pub bench: BenchConfig,
+    pub format: OutputFormat,
+    pub jobs: usize,
+    pub compare: Option<CompareOptions>,
+    /// Name of the timings table, `None` for the default one.
+    pub table: Option<String>,
+}
+
+pub fn handle(year: Year, day: Option<Day>, options: &TimeOptions) {
+    let TimeOptions {
+        run_all,
+        store,
+        ref bench,
+        format,
+        jobs,
+        compare: ref compare_options,
+        ref table,
+    } = *options;
+    let compare_options = compare_options.as_ref();
+    let table = table.as_deref();
+
+    // NOTE: never store over timings that could not be parsed.
+    let stored_timings = Timings::read_from_file(year, table).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    });
+
+    let baseline = compare_options.map(|options| match &options.baseline {
+        Some(path) => Timings::read_from_path(path).unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }),
+        None => stored_timings.clone(),
+    });
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                year.days().collect()
             } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                // when the `--all` flag is not set, filter out days that are fully benched,
+                // unless they have a baseline to compare with.
+                let has_baseline = |day: Day| {
+                    baseline
+                        .as_ref()
+                        .is_some_and(|baseline| baseline.data.iter().any(|t| t.day == day))
+                };
+                year.days()
+                    .filter(|&day| !stored_timings.is_day_complete(day) || has_baseline(day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(year, &days_to_run, true, Some(bench), format, jobs).unwrap();
+
+    let regressions = match (compare_options, baseline) {
+        (Some(options), Some(baseline)) => {
+            let is_human = format == OutputFormat::Human;
+            if is_human {
+                println!();
+            }
+            // keep stdout reserved for records when outputting JSON.
+            print_deltas(
+                &compare(&baseline, &timings),
+                options.threshold_pct,
+                !is_human,
+            )
+        }
+        _ => 0,
+    };
+
+    // NOTE: do not overwrite the baseline with regressed timings.
+    if store && regressions > 0 {
+        eprintln!("Not storing benchmarks: {regressions} regression(s) above threshold.");
+    } else if store {
+        let run = RunInfo::current("release");
+        let entries: Vec<HistoryEntry> = timings
+            .data
+            .iter()
+            .map(|timing| HistoryEntry::from_timing(timing, &run))
+            .collect();
+
+        if let Err(e) = History::append_file(year, &entries) {
+            eprintln!("Failed to append to benchmark history: {e}");
+        }
 
-    if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year, table).unwrap();
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings, table) {
+            // keep stdout reserved for records when outputting JSON.
+            Ok(()) if format == OutputFormat::Json => {
+                eprintln!("Stored updated benchmarks.");
+            }
             Ok(()) => {
+                println!();
                 println!("Stored updated benchmarks.");
             }
             Err(_) => {
@@ -37,4 +133,8 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if regressions > 0 {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,78 @@
+use std::{collections::BTreeMap, process};
+
+use crate::template::{
+    answers::{Answers, Verification},
+    output::{PartRecord, Stage},
+    run_multi::child_commands,
+    Day, Part, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Runs the given day (or all days) and compares the answers with the accepted answers.
+/// Exits with a non-zero status if any answer differs.
+pub fn handle(year: Year, day: Option<Day>, is_release: bool, jobs: usize) {
+    let answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read answers: {e}");
+        process::exit(1);
+    });
+    let days: Vec<Day> = day.map_or_else(|| year.days().collect(), |day| vec![day]);
+
+    let mut records: BTreeMap<Day, Vec<PartRecord>> = BTreeMap::new();
+    let result = child_commands::run_solutions(year, &days, None, is_release, jobs, |record| {
+        records.entry(record.day).or_default().push(record);
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to run solutions: {e}");
+        process::exit(1);
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        let day_records = records.get(&day).map_or(&[][..], Vec::as_slice);
+        let mut printed_header = false;
+
+        for part in Part::ALL {
+            let answer = day_records
+                .iter()
+                .find(|record| record.stage == Stage::Part(part))
+                .and_then(|record| record.answer.as_deref());
+
+            let verification = answers.verify(day, part, answer);
+
+            // unsolved parts without an accepted answer are not worth reporting.
+            if answer.is_none() && verification == Verification::Missing {
+                continue;
+            }
+
+            if !printed_header {
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+                printed_header = true;
+            }
+
+            let answer = answer.unwrap_or("no answer");
+            match verification {
+                Verification::Pass => {
+                    passed += 1;
+                    println!("Part {part}: ✔ {answer}");
+                }
+                Verification::Fail { expected } => {
+                    failed += 1;
+                    println!("Part {part}: ✖ {answer} (expected {expected})");
+                }
+                Verification::Missing => {
+                    missing += 1;
+                    println!("Part {part}: ? {answer} (no accepted answer)");
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{ANSI_BOLD}Verified:{ANSI_RESET} {passed} passed, {failed} failed, {missing} missing."
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,188 @@
+//! Compares benchmark timings against a baseline to catch performance regressions.
+
+use std::time::Duration;
+
+use crate::template::output::Stage;
+use crate::template::timings::Timings;
+use crate::template::{Day, Part, ANSI_BOLD, ANSI_RESET};
+
+/// Change of the mean duration of a single stage relative to the baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delta {
+    pub day: Day,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change in percent, positive if the stage got slower.
+    pub change_pct: f64,
+}
+
+impl Delta {
+    pub fn is_regression(&self, threshold_pct: f64) -> bool {
+        self.change_pct > threshold_pct
+    }
+}
+
+/// Computes deltas for every stage that has been benched both in `baseline` and `current`.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<Delta> {
+    let mut deltas = vec![];
+
+    for timing in &current.data {
+        let Some(base) = baseline.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for stage in [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)] {
+            if let (Some(before), Some(after)) = (base.stage(stage), timing.stage(stage)) {
+                deltas.push(Delta {
+                    day: timing.day,
+                    stage,
+                    baseline: before.duration(),
+                    current: after.duration(),
+                    change_pct: change_pct(before.duration(), after.duration()),
+                });
+            }
+        }
+    }
+
+    deltas
+}
+
+/// Prints deltas, highlighting those that exceed `threshold_pct`. Returns the number of regressions.
+pub fn print_deltas(deltas: &[Delta], threshold_pct: f64, to_stderr: bool) -> usize {
+    let mut lines = vec![format!(
+        "{ANSI_BOLD}Comparison{ANSI_RESET} (threshold +{threshold_pct}%)"
+    )];
+
+    if deltas.is_empty() {
+        lines.push("No stored timings to compare with.".into());
+    }
+
+    for delta in deltas {
+        let marker = if delta.is_regression(threshold_pct) {
+            " ✖ regression"
+        } else {
+            ""
+        };
+
+        lines.push(format!(
+            "Day {} {:<7} {:>10.1?} → {:>10.1?} ({:+.1}%){marker}",
+            delta.day,
+            format!("{}:", delta.stage),
+            delta.baseline,
+            delta.current,
+            delta.change_pct,
+        ));
+    }
+
+    for line in lines {
+        if to_stderr {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    deltas
+        .iter()
+        .filter(|delta| delta.is_regression(threshold_pct))
+        .count()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn change_pct(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_nanos() as f64;
+    let current = current.as_nanos() as f64;
+
+    if baseline == 0.0 {
+        0.0
+    } else {
+        (current - baseline) / baseline * 100.0
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::compare;
+    use crate::{
+        day,
+        template::{
+            output::Stage,
+            stats::BenchStats,
+            timings::{StageTiming, Timing, Timings},
+            Day, Part,
+        },
+    };
+    use std::time::Duration;
+
+    fn stage(nanos: u64) -> Option<StageTiming> {
+        BenchStats::from_samples(&[Duration::from_nanos(nanos)]).map(StageTiming::from_stats)
+    }
+
+    fn timing(day: Day, parse: Option<u64>, part_1: Option<u64>, part_2: Option<u64>) -> Timing {
+        Timing {
+            day,
+            parse: parse.and_then(stage),
+            part_1: part_1.and_then(stage),
+            part_2: part_2.and_then(stage),
+            total_nanos: 0_f64,
+        }
+    }
+
+    #[test]
+    fn computes_deltas() {
+        let baseline = Timings {
+            data: vec![timing(day!(1), Some(100), Some(200), Some(400))],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), Some(100), Some(100), Some(1200))],
+        };
+
+        let deltas = compare(&baseline, &current);
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].stage, Stage::Parse);
+        assert!(deltas[0].change_pct.abs() < f64::EPSILON);
+        assert!((deltas[1].change_pct + 50.0).abs() < f64::EPSILON);
+        assert!((deltas[2].change_pct - 200.0).abs() < f64::EPSILON);
+        assert_eq!(deltas[2].baseline, Duration::from_nanos(400));
+        assert_eq!(deltas[2].current, Duration::from_nanos(1200));
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let baseline = Timings {
+            data: vec![timing(day!(1), None, Some(100), Some(100))],
+        };
+        let current = Timings {
+            data: vec![timing(day!(1), None, Some(105), Some(120))],
+        };
+
+        let deltas = compare(&baseline, &current);
+        assert!(!deltas[0].is_regression(10.0));
+        assert!(deltas[1].is_regression(10.0));
+        assert!(!deltas[1].is_regression(25.0));
+    }
+
+    #[test]
+    fn skips_stages_without_baseline() {
+        let baseline = Timings {
+            data: vec![
+                timing(day!(1), None, Some(100), None),
+                timing(day!(3), Some(1), Some(1), Some(1)),
+            ],
+        };
+        let current = Timings {
+            data: vec![
+                timing(day!(1), Some(10), Some(100), Some(100)),
+                timing(day!(2), Some(10), Some(100), Some(100)),
+            ],
+        };
+
+        let deltas = compare(&baseline, &current);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].day, day!(1));
+        assert_eq!(deltas[0].stage, Stage::Part(Part::One));
+    }
+}
@@ -0,0 +1,403 @@
+//! Renders a single, offline HTML page with the progress of all days.
+
+use std::{fmt::Write, fs, path::Path};
+
+use crate::template::answers::Answers;
+use crate::template::chart::render_svg;
+use crate::template::history::{format_nanos, format_timestamp, History, HistoryEntry};
+use crate::template::puzzle::puzzle_title;
+use crate::template::readme_benchmarks::get_path_for_bin;
+use crate::template::timings::{StageTiming, Timing, Timings};
+use crate::template::{Part, PuzzleId, Year};
+
+/// How far a day has been solved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolveStatus {
+    NotScaffolded,
+    Unsolved,
+    PartOne,
+    PartTwo,
+    Complete,
+}
+
+impl SolveStatus {
+    /// Status of a scaffolded day from which of its parts have an accepted answer, indexed by part.
+    pub fn new(is_scaffolded: bool, solved_parts: [bool; 2]) -> Self {
+        match (is_scaffolded, solved_parts) {
+            (false, _) => Self::NotScaffolded,
+            (true, [false, false]) => Self::Unsolved,
+            (true, [true, false]) => Self::PartOne,
+            (true, [false, true]) => Self::PartTwo,
+            (true, [true, true]) => Self::Complete,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::NotScaffolded => "not scaffolded",
+            Self::Unsolved => "unsolved",
+            Self::PartOne => "part 1 only",
+            Self::PartTwo => "part 2 only",
+            Self::Complete => "complete",
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Self::NotScaffolded => "not-scaffolded",
+            Self::Unsolved => "unsolved",
+            Self::PartOne => "part-one",
+            Self::PartTwo => "part-two",
+            Self::Complete => "complete",
+        }
+    }
+}
+
+/// Everything the dashboard shows about a single day.
+#[derive(Clone, Debug)]
+pub struct DayOverview {
+    pub id: PuzzleId,
+    pub status: SolveStatus,
+    pub title: Option<String>,
+    pub timing: Option<Timing>,
+    pub history: Vec<HistoryEntry>,
+    /// File name and content of each example file.
+    pub examples: Vec<(String, String)>,
+    pub puzzle: Option<String>,
+}
+
+impl DayOverview {
+    /// Collects the overview of a day from the files in `data/<year>` and `src/bin`.
+    pub fn collect(id: PuzzleId, timings: &Timings, history: &History, answers: &Answers) -> Self {
+        let day = id.day;
+        let puzzle = fs::read_to_string(id.puzzle_path()).ok();
+
+        // NOTE: puzzle descriptions downloaded after solving a part contain its answer.
+        let puzzle_answers = puzzle.as_deref().map(puzzle_answers).unwrap_or_default();
+        let solved_parts = Part::ALL.map(|part| {
+            answers.get(day, part).is_some()
+                || puzzle_answers.len() >= usize::from(part.into_inner())
+        });
+
+        Self {
+            id,
+            status: SolveStatus::new(Path::new(&get_path_for_bin(id)).exists(), solved_parts),
+            title: puzzle.as_deref().and_then(puzzle_title),
+            timing: timings.data.iter().find(|t| t.day == day).cloned(),
+            history: history.for_day(day).into_iter().cloned().collect(),
+            examples: read_examples(id),
+            puzzle,
+        }
+    }
+}
+
+/// Reads `data/<year>/examples/NN.txt` and `data/<year>/examples/NN-K.txt`, sorted by file name.
+fn read_examples(id: PuzzleId) -> Vec<(String, String)> {
+    let Ok(entries) = fs::read_dir(id.year.data_path("examples")) else {
+        return vec![];
+    };
+    let day = id.day;
+
+    let mut examples: Vec<(String, String)> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let stem = name.strip_suffix(".txt")?;
+            let is_match = stem == day.to_string()
+                || stem
+                    .strip_prefix(&format!("{day}-"))
+                    .is_some_and(|n| n.parse::<u8>().is_ok());
+            let content = fs::read_to_string(entry.path()).ok()?;
+            is_match.then_some((name, content))
+        })
+        .collect();
+
+    examples.sort();
+    examples
+}
+
+/// Extracts the answers of solved parts from a puzzle description.
+pub fn puzzle_answers(markdown: &str) -> Vec<String> {
+    markdown
+        .match_indices("Your puzzle answer was")
+        .filter_map(|(i, _)| {
+            let rest = &markdown[i..];
+            let start = rest.find('`')? + 1;
+            let end = start + rest[start..].find('`')?;
+            Some(rest[start..end].to_string())
+        })
+        .collect()
+}
+
+/// Collects all days and renders the dashboard with the timings of a table. Links to solutions are relative to `output`.
+pub fn build(year: Year, table: Option<&str>, output: &str) -> String {
+    let timings = Timings::read_from_file(year, table)
+        .unwrap_or_else(|e| {
+            eprintln!("Ignoring timings: {e}");
+            Timings::default()
+        })
+        .in_year(year);
+    let history = History::read_from_file(year);
+    let answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Ignoring answers: {e}");
+        Answers::default()
+    });
+
+    let days: Vec<DayOverview> = year
+        .days()
+        .map(|day| DayOverview::collect(PuzzleId::new(year, day), &timings, &history, &answers))
+        .collect();
+
+    render_html(&days, &timings, &link_prefix(output))
+}
+
+/// Prefix that leads from the directory of `output` back to the repository root.
+fn link_prefix(output: &str) -> String {
+    let depth = Path::new(output)
+        .parent()
+        .map_or(0, |parent| parent.components().count());
+    "../".repeat(depth)
+}
+
+/// Renders the dashboard page. `link_prefix` is prepended to links into the repository.
+pub fn render_html(days: &[DayOverview], timings: &Timings, link_prefix: &str) -> String {
+    let count = |status: SolveStatus| days.iter().filter(|d| d.status == status).count();
+
+    let mut html = String::new();
+
+    // NOTE: writing to a `String` does not fail.
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>Advent of Code dashboard</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<h1>🎄 Advent of Code</h1>
+<p class=\"summary\">
+<span class=\"complete\">{} complete</span>
+<span class=\"part-one\">{} part 1 only</span>
+<span class=\"part-two\">{} part 2 only</span>
+<span class=\"unsolved\">{} unsolved</span>
+<span class=\"not-scaffolded\">{} not scaffolded</span>
+</p>
+",
+        count(SolveStatus::Complete),
+        count(SolveStatus::PartOne),
+        count(SolveStatus::PartTwo),
+        count(SolveStatus::Unsolved),
+        count(SolveStatus::NotScaffolded),
+    );
+
+    if !timings.data.is_empty() {
+        let _ = write!(
+            html,
+            "<h2>Benchmarks</h2>\n{}<p>Total: {:.2}ms</p>\n",
+            render_svg(timings),
+            timings.total_millis()
+        );
+    }
+
+    html.push_str(
+        "<h2>Days</h2>
+<table>
+<tr><th>Day</th><th>Status</th><th>Parse</th><th>Part 1</th><th>Part 2</th><th>History</th><th>Examples</th><th>Puzzle</th></tr>
+",
+    );
+
+    for day in days {
+        render_day(&mut html, day, link_prefix);
+    }
+
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+fn render_day(html: &mut String, day: &DayOverview, link_prefix: &str) {
+    let stage = |stage: Option<StageTiming>| stage.map_or_else(|| "-".into(), |s| s.to_string());
+    let timing = day.timing.as_ref();
+
+    let name = match day.status {
+        SolveStatus::NotScaffolded => format!("Day {}", day.id.day.into_inner()),
+        _ => format!(
+            "<a href=\"{link_prefix}{}\">Day {}</a>",
+            escape_html(get_path_for_bin(day.id).trim_start_matches("./")),
+            day.id.day.into_inner()
+        ),
+    };
+
+    let title = day
+        .title
+        .as_deref()
+        .map(|t| format!("<br><small>{}</small>", escape_html(t)))
+        .unwrap_or_default();
+
+    let _ = writeln!(
+        html,
+        "<tr class=\"{}\"><td>{name}{title}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+        day.status.class(),
+        day.status.label(),
+        stage(timing.and_then(|t| t.parse)),
+        stage(timing.and_then(|t| t.part_1)),
+        stage(timing.and_then(|t| t.part_2)),
+        render_history(&day.history),
+        render_examples(&day.examples),
+        day.puzzle.as_deref().map_or_else(String::new, |puzzle| {
+            format!(
+                "<details><summary>Show</summary><pre>{}</pre></details>",
+                escape_html(puzzle)
+            )
+        }),
+    );
+}
+
+fn render_history(history: &[HistoryEntry]) -> String {
+    let Some(latest) = history.last() else {
+        return String::new();
+    };
+
+    let best = history
+        .iter()
+        .filter(|entry| entry.has_same_stages(latest))
+        .min_by(|a, b| a.total_nanos().total_cmp(&b.total_nanos()))
+        .unwrap_or(latest);
+
+    let rows: String = history
+        .iter()
+        .map(|entry| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                format_timestamp(entry.run.timestamp),
+                escape_html(entry.run.commit.as_deref().unwrap_or("-")),
+                format_nanos(Some(entry.total_nanos()))
+            )
+        })
+        .collect();
+
+    format!(
+        "best {}, latest {}<details><summary>{} runs</summary><table>{rows}</table></details>",
+        format_nanos(Some(best.total_nanos())),
+        format_nanos(Some(latest.total_nanos())),
+        history.len()
+    )
+}
+
+fn render_examples(examples: &[(String, String)]) -> String {
+    examples
+        .iter()
+        .map(|(name, content)| {
+            format!(
+                "<details><summary>{}</summary><pre>{}</pre></details>",
+                escape_html(name),
+                escape_html(content)
+            )
+        })
+        .collect()
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2rem; color: #212121; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #e0e0e0; padding: 0.3rem 0.6rem; text-align: left; vertical-align: top; }
+pre { max-width: 40rem; max-height: 20rem; overflow: auto; background: #fafafa; padding: 0.5rem; }
+.summary span { margin-right: 1rem; padding: 0.2rem 0.5rem; border-radius: 0.3rem; }
+.complete { background: #e8f5e9; }
+.part-one, .part-two { background: #fff8e1; }
+.unsolved { background: #ffebee; }
+.not-scaffolded { color: #9e9e9e; }
+";
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{link_prefix, puzzle_answers, render_html, DayOverview, SolveStatus};
+    use crate::{
+        day,
+        template::{timings::Timings, PuzzleId},
+        year,
+    };
+
+    const PUZZLE: &str = r"\--- Day 1: Historian Hysteria ---
+----------
+
+The *Chief Historian* is always present.
+
+Your puzzle answer was `11`.
+
+\--- Part Two ---
+----------
+
+Your puzzle answer was `31`.
+";
+
+    #[test]
+    fn derives_status() {
+        assert_eq!(
+            SolveStatus::new(false, [true, true]),
+            SolveStatus::NotScaffolded
+        );
+        assert_eq!(
+            SolveStatus::new(true, [false, false]),
+            SolveStatus::Unsolved
+        );
+        assert_eq!(SolveStatus::new(true, [true, false]), SolveStatus::PartOne);
+        assert_eq!(SolveStatus::new(true, [false, true]), SolveStatus::PartTwo);
+        assert_eq!(SolveStatus::new(true, [true, true]), SolveStatus::Complete);
+    }
+
+    #[test]
+    fn parses_puzzle_answers() {
+        assert_eq!(puzzle_answers(PUZZLE), vec!["11", "31"]);
+        assert!(puzzle_answers("unsolved").is_empty());
+    }
+
+    #[test]
+    fn computes_link_prefix() {
+        assert_eq!(link_prefix("dashboard.html"), "");
+        assert_eq!(link_prefix("data/dashboard.html"), "../");
+        assert_eq!(link_prefix("out/html/index.html"), "../../");
+    }
+
+    #[test]
+    fn renders_days() {
+        let days = vec![
+            DayOverview {
+                id: PuzzleId::new(year!(2024), day!(1)),
+                status: SolveStatus::Complete,
+                title: Some("Historian Hysteria".into()),
+                timing: None,
+                history: vec![],
+                examples: vec![("01.txt".into(), "3   4\n<4>".into())],
+                puzzle: Some(PUZZLE.into()),
+            },
+            DayOverview {
+                id: PuzzleId::new(year!(2024), day!(2)),
+                status: SolveStatus::NotScaffolded,
+                title: None,
+                timing: None,
+                history: vec![],
+                examples: vec![],
+                puzzle: None,
+            },
+        ];
+
+        let html = render_html(&days, &Timings::default(), "../");
+        assert!(html.contains("<span class=\"complete\">1 complete</span>"));
+        assert!(html.contains("<span class=\"not-scaffolded\">1 not scaffolded</span>"));
+        assert!(html.contains("<a href=\"../src/bin/2024-01.rs\">Day 1</a>"));
+        assert!(!html.contains("src/bin/2024-02.rs"));
+        assert!(html.contains("3   4\n&lt;4&gt;"));
+        assert!(!html.contains("<h2>Benchmarks</h2>"));
+    }
+}
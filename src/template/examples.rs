@@ -0,0 +1,295 @@
+//! Example files of a day and the tests that are scaffolded for them.
+//! A day either has a single example `NN.txt` or several examples `NN-K.txt`, read with `read_file_part`.
+
+use std::fs;
+
+use crate::template::{Day, Part, PuzzleId};
+
+/// A single example file, `NN.txt` without a suffix or `NN-K.txt` with suffix `K`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExampleFile {
+    pub suffix: Option<u8>,
+}
+
+impl ExampleFile {
+    /// File of the `n`-th (zero-based) example of a puzzle: `NN.txt`, `NN-2.txt`, `NN-3.txt`, ...
+    pub fn nth(n: usize) -> Self {
+        Self {
+            suffix: (n > 0).then(|| u8::try_from(n + 1).unwrap_or(u8::MAX)),
+        }
+    }
+
+    /// File of the `n`-th (zero-based) example, following the naming of the existing `files` of the day.
+    /// Days whose examples are numbered from `NN-1.txt` continue as `NN-2.txt`, `NN-3.txt`, ...
+    pub fn nth_of(n: usize, files: &[ExampleFile]) -> Self {
+        if files.contains(&Self { suffix: Some(1) }) {
+            Self {
+                suffix: Some(u8::try_from(n + 1).unwrap_or(u8::MAX)),
+            }
+        } else {
+            Self::nth(n)
+        }
+    }
+
+    pub fn path(self, id: PuzzleId) -> String {
+        match self.suffix {
+            Some(suffix) => id
+                .year
+                .data_path(&format!("examples/{}-{suffix}.txt", id.day)),
+            None => id.data_path("examples"),
+        }
+    }
+
+    /// Parses a file name like `12-3.txt` into a file of the given day.
+    fn from_file_name(day: Day, name: &str) -> Option<Self> {
+        let stem = name.strip_suffix(".txt")?;
+        if stem == day.to_string() {
+            return Some(Self { suffix: None });
+        }
+
+        let suffix = stem.strip_prefix(&format!("{day}-"))?.parse().ok()?;
+        Some(Self {
+            suffix: Some(suffix),
+        })
+    }
+}
+
+/// Expected answer of one part for one example file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub file: ExampleFile,
+    pub part: Part,
+    pub answer: String,
+}
+
+/// Lists the existing example files of a puzzle in order.
+pub fn find_example_files(id: PuzzleId) -> Vec<ExampleFile> {
+    let mut files: Vec<ExampleFile> = fs::read_dir(id.year.data_path("examples"))
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    ExampleFile::from_file_name(id.day, &entry.file_name().to_string_lossy())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    files.sort();
+    files
+}
+
+/// Rust type that fits all expected answers of a part: `usize` unless an answer is negative or not a number.
+pub fn answer_type(expected: &[Expected], part: Part) -> &'static str {
+    let answers = expected
+        .iter()
+        .filter(|e| e.part == part)
+        .map(|e| &e.answer);
+    let mut answer_type = "usize";
+
+    for answer in answers {
+        match answer.parse::<i128>() {
+            Ok(n) if n < 0 => answer_type = "i64",
+            Ok(_) => {}
+            Err(_) => return "String",
+        }
+    }
+
+    answer_type
+}
+
+/// Literal of an expected answer of the given type.
+fn answer_literal(answer: &str, answer_type: &str) -> String {
+    if answer_type == "String" {
+        format!("Some({answer:?}.to_string())")
+    } else {
+        format!("Some({answer})")
+    }
+}
+
+/// Renders one test per part and example file with a known expected answer.
+/// Answers are written as literals of the part's [`answer_type`].
+/// Parts without any known answer get a single test on the first example file that asserts `None`.
+pub fn render_tests(day: Day, files: &[ExampleFile], expected: &[Expected]) -> String {
+    let mut tests = vec![];
+
+    for part in Part::ALL {
+        let answer_type = answer_type(expected, part);
+        let known: Vec<(ExampleFile, String)> = files
+            .iter()
+            .filter_map(|file| {
+                expected
+                    .iter()
+                    .rfind(|e| e.file == *file && e.part == part)
+                    .map(|e| (*file, answer_literal(&e.answer, answer_type)))
+            })
+            .collect();
+
+        let cases = if known.is_empty() {
+            let first = files
+                .first()
+                .copied()
+                .unwrap_or(ExampleFile { suffix: None });
+            vec![(first, "None".to_string())]
+        } else {
+            known
+        };
+
+        for (file, value) in cases {
+            tests.push(render_test(day, part, file, &value));
+        }
+    }
+
+    tests.join("\n\n")
+}
+
+fn render_test(day: Day, part: Part, file: ExampleFile, value: &str) -> String {
+    let day_number = day.into_inner();
+    let part_name = part.name();
+
+    let (name_suffix, read) = match file.suffix {
+        Some(suffix) => (
+            format!("_{suffix}"),
+            format!(
+                "part_{part_name}(&advent_of_code::template::read_file_part(\n            \"examples\", PUZZLE, {suffix},\n        ));"
+            ),
+        ),
+        None => (
+            String::new(),
+            format!("part_{part_name}(&advent_of_code::template::read_file(\"examples\", PUZZLE));"),
+        ),
+    };
+
+    format!(
+        "    #[test]
+    fn test_day_{day_number}_part_{part_name}_from_example{name_suffix}() {{
+        let result = {read}
+        assert_eq!(result, {value});
+    }}"
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_type, render_tests, ExampleFile, Expected};
+    use crate::{
+        day,
+        template::{Part, PuzzleId},
+        year,
+    };
+
+    const MAIN: ExampleFile = ExampleFile { suffix: None };
+
+    fn expected(suffix: Option<u8>, part: Part, answer: &str) -> Expected {
+        Expected {
+            file: ExampleFile { suffix },
+            part,
+            answer: answer.into(),
+        }
+    }
+
+    #[test]
+    fn maps_examples_to_files() {
+        assert_eq!(ExampleFile::nth(0), MAIN);
+        assert_eq!(
+            ExampleFile::nth(1).path(PuzzleId::new(year!(2024), day!(3))),
+            "data/2024/examples/03-2.txt"
+        );
+        assert_eq!(
+            ExampleFile::from_file_name(day!(12), "12-3.txt"),
+            Some(ExampleFile { suffix: Some(3) })
+        );
+        assert_eq!(ExampleFile::from_file_name(day!(12), "12.txt"), Some(MAIN));
+        assert_eq!(ExampleFile::nth_of(0, &[]), MAIN);
+        assert_eq!(ExampleFile::nth_of(0, &[MAIN]), MAIN);
+        assert_eq!(
+            ExampleFile::nth_of(0, &[ExampleFile { suffix: Some(1) }]),
+            ExampleFile { suffix: Some(1) }
+        );
+        assert_eq!(
+            ExampleFile::nth_of(4, &[ExampleFile { suffix: Some(1) }]),
+            ExampleFile { suffix: Some(5) }
+        );
+        assert_eq!(ExampleFile::from_file_name(day!(12), "02.txt"), None);
+        assert_eq!(ExampleFile::from_file_name(day!(12), "12-x.txt"), None);
+    }
+
+    #[test]
+    fn renders_unknown_answers() {
+        let tests = render_tests(day!(1), &[MAIN], &[]);
+        assert_eq!(
+            tests,
+            r#"    #[test]
+    fn test_day_1_part_one_from_example() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_day_1_part_two_from_example() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }"#
+        );
+    }
+
+    #[test]
+    fn renders_one_test_per_example_file() {
+        let files = [1, 2, 3].map(|suffix| ExampleFile {
+            suffix: Some(suffix),
+        });
+        let tests = render_tests(
+            day!(12),
+            &files,
+            &[
+                expected(Some(1), Part::One, "140"),
+                expected(Some(2), Part::One, "772"),
+                expected(Some(3), Part::Two, "4,6,3"),
+            ],
+        );
+
+        assert_eq!(tests.matches("#[test]").count(), 3);
+        assert!(tests.contains(
+            r#"    fn test_day_12_part_one_from_example_2() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", PUZZLE, 2,
+        ));
+        assert_eq!(result, Some(772));
+    }"#
+        ));
+        assert!(tests.contains("fn test_day_12_part_two_from_example_3() {"));
+        assert!(tests.contains(r#"assert_eq!(result, Some("4,6,3".to_string()));"#));
+    }
+
+    #[test]
+    fn infers_answer_types() {
+        let answers = [
+            expected(None, Part::One, "11"),
+            expected(None, Part::Two, "-3"),
+            expected(Some(2), Part::Two, "4"),
+        ];
+        assert_eq!(answer_type(&answers, Part::One), "usize");
+        assert_eq!(answer_type(&answers, Part::Two), "i64");
+        assert_eq!(
+            answer_type(&[expected(None, Part::One, "abc")], Part::One),
+            "String"
+        );
+        assert_eq!(answer_type(&[], Part::One), "usize");
+    }
+
+    #[test]
+    fn prefers_later_answers() {
+        let tests = render_tests(
+            day!(1),
+            &[MAIN],
+            &[
+                expected(None, Part::One, "11"),
+                expected(None, Part::One, "12"),
+            ],
+        );
+        assert!(tests.contains("Some(12)"));
+        assert!(!tests.contains("Some(11)"));
+    }
+}
@@ -0,0 +1,359 @@
+//! Append-only history of stored benchmark runs.
+//! Every `cargo time --store` appends one line of JSON per benched day to `data/<year>/timings.history.jsonl`.
+
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::timings::Timing;
+use crate::template::{Day, Year};
+
+/// Name of the history file in the data directory of a year.
+static HISTORY_FILE_NAME: &str = "timings.history.jsonl";
+
+/// Describes the environment a benchmark run was recorded in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunInfo {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub hostname: Option<String>,
+    pub profile: String,
+}
+
+impl RunInfo {
+    /// Collects information about the current run. Commit and hostname are best-effort.
+    pub fn current(profile: &str) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            commit: git_commit(),
+            hostname: hostname(),
+            profile: profile.into(),
+        }
+    }
+}
+
+/// A single day of a stored benchmark run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub run: RunInfo,
+    pub day: Day,
+    pub parse_nanos: Option<f64>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+}
+
+impl HistoryEntry {
+    pub fn from_timing(timing: &Timing, run: &RunInfo) -> Self {
+        Self {
+            run: run.clone(),
+            day: timing.day,
+            parse_nanos: timing.parse.map(|t| t.nanos),
+            part_1_nanos: timing.part_1.map(|t| t.nanos),
+            part_2_nanos: timing.part_2.map(|t| t.nanos),
+        }
+    }
+
+    /// Duration of the parse stage and both parts combined.
+    pub fn total_nanos(&self) -> f64 {
+        [self.parse_nanos, self.part_1_nanos, self.part_2_nanos]
+            .iter()
+            .flatten()
+            .sum()
+    }
+
+    /// Whether both runs timed the same stages, so that their totals can be compared.
+    pub fn has_same_stages(&self, other: &Self) -> bool {
+        self.parse_nanos.is_some() == other.parse_nanos.is_some()
+            && self.part_1_nanos.is_some() == other.part_1_nanos.is_some()
+            && self.part_2_nanos.is_some() == other.part_2_nanos.is_some()
+    }
+}
+
+/// All recorded runs, in the order they were recorded.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Appends entries to the history file of a year, creating it if necessary.
+    pub fn append_file(year: Year, entries: &[HistoryEntry]) -> Result<(), Error> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(year.data_path(HISTORY_FILE_NAME))?;
+
+        for entry in entries {
+            // NOTE: serializing a JSON object built from strings and numbers does not fail.
+            writeln!(file, "{}", JsonValue::from(entry).stringify().unwrap())?;
+        }
+
+        Ok(())
+    }
+
+    /// Rehydrate the history of a year from its file. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(year.data_path(HISTORY_FILE_NAME))
+            .map(|content| History::from_lines(&content))
+            .unwrap_or_default()
+    }
+
+    /// Parses one entry per line. Malformed lines are reported and skipped.
+    pub fn from_lines(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(i, line)| match HistoryEntry::try_from(line) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    eprintln!("Skipping line {} of history: {e}", i + 1);
+                    None
+                }
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    pub fn for_day(&self, day: Day) -> Vec<&HistoryEntry> {
+        self.entries.iter().filter(|e| e.day == day).collect()
+    }
+
+    /// The recorded run of a day with the lowest combined duration.
+    /// Only runs that timed the same stages as the latest run are compared,
+    /// so that a run without part 2 does not beat every complete one.
+    pub fn best(&self, day: Day) -> Option<&HistoryEntry> {
+        let latest = self.latest(day)?;
+        self.entries
+            .iter()
+            .filter(|e| e.day == day && e.has_same_stages(latest))
+            .min_by(|a, b| a.total_nanos().total_cmp(&b.total_nanos()))
+    }
+
+    /// The most recently recorded run of a day.
+    pub fn latest(&self, day: Day) -> Option<&HistoryEntry> {
+        self.entries.iter().rfind(|e| e.day == day)
+    }
+}
+
+/// Formats seconds since the unix epoch as a UTC date, e.g. `2024-12-08 14:03 UTC`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // civil date from days since epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/// Formats a duration in nanoseconds the same way as the benchmark output.
+pub fn format_nanos(nanos: Option<f64>) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    nanos.map_or_else(
+        || "-".into(),
+        |n| format!("{:.1?}", Duration::from_nanos(n as u64)),
+    )
+}
+
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !commit.is_empty()).then_some(commit)
+}
+
+fn hostname() -> Option<String> {
+    env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| {
+            let output = Command::new("hostname").output().ok()?;
+            String::from_utf8(output.stdout).ok()
+        })
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        let optional_string =
+            |s: &Option<String>| s.clone().map_or(JsonValue::Null, JsonValue::String);
+        let optional_number = |n: Option<f64>| n.map_or(JsonValue::Null, JsonValue::Number);
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.run.timestamp as f64),
+        );
+        map.insert("commit".into(), optional_string(&value.run.commit));
+        map.insert("hostname".into(), optional_string(&value.run.hostname));
+        map.insert(
+            "profile".into(),
+            JsonValue::String(value.run.profile.clone()),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("parse_nanos".into(), optional_number(value.parse_nanos));
+        map.insert("part_1_nanos".into(), optional_number(value.part_1_nanos));
+        map.insert("part_2_nanos".into(), optional_number(value.part_2_nanos));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let optional_string = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+            Some(_) => Err(format!("Expected entry.{key} to be null or string.")),
+        };
+
+        let optional_number = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::Number(n)) => Ok(Some(*n)),
+            Some(_) => Err(format!("Expected entry.{key} to be null or number.")),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|&n| n as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.profile to be a string.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        Ok(HistoryEntry {
+            run: RunInfo {
+                timestamp,
+                commit: optional_string("commit")?,
+                hostname: optional_string("hostname")?,
+                profile: profile.clone(),
+            },
+            day,
+            parse_nanos: optional_number("parse_nanos")?,
+            part_1_nanos: optional_number("part_1_nanos")?,
+            part_2_nanos: optional_number("part_2_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_timestamp, History, HistoryEntry, RunInfo};
+    use crate::{day, template::Day};
+    use tinyjson::JsonValue;
+
+    fn entry(timestamp: u64, day: Day, part_1: f64, part_2: Option<f64>) -> HistoryEntry {
+        HistoryEntry {
+            run: RunInfo {
+                timestamp,
+                commit: Some("abc1234".into()),
+                hostname: None,
+                profile: "release".into(),
+            },
+            day,
+            parse_nanos: None,
+            part_1_nanos: Some(part_1),
+            part_2_nanos: part_2,
+        }
+    }
+
+    fn get_mock_history() -> History {
+        History {
+            entries: vec![
+                entry(1_733_000_000, day!(1), 300.0, Some(300.0)),
+                entry(1_733_000_000, day!(2), 10.0, None),
+                entry(1_733_100_000, day!(1), 100.0, Some(200.0)),
+                entry(1_733_200_000, day!(1), 200.0, Some(200.0)),
+                entry(1_733_300_000, day!(2), 20.0, Some(20.0)),
+            ],
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = entry(1_733_000_000, day!(1), 74.0, None);
+        let line = JsonValue::from(&entry).stringify().unwrap();
+        assert_eq!(HistoryEntry::try_from(line.as_str()).unwrap(), entry);
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let entry = JsonValue::from(&entry(1_733_000_000, day!(1), 74.0, None))
+            .stringify()
+            .unwrap();
+        let content = format!("{entry}\n{{\"day\":\"01\"}}\n\n{entry}\n");
+        assert_eq!(History::from_lines(&content).entries.len(), 2);
+    }
+
+    #[test]
+    fn finds_best_and_latest() {
+        let history = get_mock_history();
+        assert_eq!(history.for_day(day!(1)).len(), 3);
+        assert_eq!(history.best(day!(1)).unwrap().run.timestamp, 1_733_100_000);
+        assert_eq!(
+            history.latest(day!(1)).unwrap().run.timestamp,
+            1_733_200_000
+        );
+        assert_eq!(history.latest(day!(2)).unwrap().total_nanos(), 40.0);
+        assert_eq!(history.best(day!(2)).unwrap().total_nanos(), 40.0);
+        assert!(history.best(day!(3)).is_none());
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_timestamp(1_733_666_580), "2024-12-08 14:03 UTC");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00 UTC");
+    }
+}
@@ -0,0 +1,171 @@
+//! Where a solution binary reads its input from.
+//! Passed to solution binaries as `--input <path>`, `--input -` or `--example [N]`, and forwarded by `cargo solve`.
+
+use std::{
+    fs,
+    io::{self, Read},
+};
+
+use crate::template::examples::find_example_files;
+use crate::template::PuzzleId;
+
+/// Input of a run. Defaults to the downloaded puzzle input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/<year>/inputs`.
+    #[default]
+    Puzzle,
+    /// The `N`-th example file of the day, counting from 1. The first one if [`None`].
+    Example(Option<usize>),
+    /// Any file, e.g. a friend's input or a generated stress input.
+    Path(String),
+    /// Standard input, selected by `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads `--input <path>`, `--input -` or `--example [N]` from the arguments of a solution binary.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let input = args.iter().position(|x| x == "--input");
+        let example = args.iter().position(|x| x == "--example");
+
+        match (input, example) {
+            (Some(_), Some(_)) => Err("`--input` and `--example` cannot be combined.".into()),
+            (Some(i), None) => match args.get(i + 1).map(String::as_str) {
+                Some("-") => Ok(Self::Stdin),
+                Some(path) if !path.starts_with("--") => Ok(Self::Path(path.into())),
+                _ => Err("expecting `--input` to be followed by a path, or `-` for stdin.".into()),
+            },
+            (None, Some(i)) => match args.get(i + 1).and_then(|x| x.parse::<usize>().ok()) {
+                Some(0) => Err("expecting `--example` to count from 1.".into()),
+                n => Ok(Self::Example(n)),
+            },
+            (None, None) => Ok(Self::Puzzle),
+        }
+    }
+
+    /// Arguments that select this input in a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+            Self::Path(path) => vec!["--input".into(), path.clone()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Reads the input of the given puzzle.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<String, String> {
+        match self {
+            Self::Puzzle => {
+                let path = puzzle.data_path("inputs");
+                fs::read_to_string(&path).map_err(|e| {
+                    format!(
+                        "could not open input file \"{path}\": {e}. Download it with `cargo download {}` or pass `--input <path>`.",
+                        puzzle.day.into_inner()
+                    )
+                })
+            }
+            Self::Example(n) => {
+                let files = find_example_files(puzzle);
+                if files.is_empty() {
+                    return Err(format!("no example files found for day {}.", puzzle.day));
+                }
+
+                let index = n.unwrap_or(1);
+                let file = files.get(index - 1).ok_or_else(|| {
+                    format!(
+                        "day {} has {} example file(s), cannot read example {index}.",
+                        puzzle.day,
+                        files.len()
+                    )
+                })?;
+                read_path(&file.path(puzzle))
+            }
+            Self::Path(path) => read_path(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read input from stdin: {e}"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_path(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not open input file \"{path}\": {e}"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+    use crate::{day, template::PuzzleId, year};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(
+            InputSource::from_args(&args(&["--format", "human"])),
+            Ok(InputSource::Puzzle)
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--input", "friend.txt", "--time"])),
+            Ok(InputSource::Path("friend.txt".into()))
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--input", "-"])),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--example", "--format", "json"])),
+            Ok(InputSource::Example(None))
+        );
+        assert_eq!(
+            InputSource::from_args(&args(&["--example", "2"])),
+            Ok(InputSource::Example(Some(2)))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input_sources() {
+        assert!(InputSource::from_args(&args(&["--input"])).is_err());
+        assert!(InputSource::from_args(&args(&["--input", "--time"])).is_err());
+        assert!(InputSource::from_args(&args(&["--example", "0"])).is_err());
+        assert!(InputSource::from_args(&args(&["--input", "a.txt", "--example"])).is_err());
+    }
+
+    #[test]
+    fn roundtrips_input_sources() {
+        for source in [
+            InputSource::Puzzle,
+            InputSource::Example(None),
+            InputSource::Example(Some(3)),
+            InputSource::Path("inputs/stress.txt".into()),
+            InputSource::Stdin,
+        ] {
+            assert_eq!(InputSource::from_args(&source.to_args()), Ok(source));
+        }
+    }
+
+    #[test]
+    fn explains_missing_files() {
+        let puzzle = PuzzleId::new(year!(2015), day!(25));
+        let error = InputSource::Puzzle.read(puzzle).unwrap_err();
+        assert!(error.contains("data/2015/inputs/25.txt"));
+        assert!(error.contains("cargo download 25"));
+
+        let error = InputSource::Example(Some(4)).read(puzzle).unwrap_err();
+        assert_eq!(error, "no example files found for day 25.");
+
+        let error = InputSource::Example(None).read(puzzle).unwrap_err();
+        assert_eq!(error, "no example files found for day 25.");
+    }
+}
@@ -0,0 +1,134 @@
+//! Moves data written before solutions were scoped by year.
+//! Older checkouts keep their files directly in `data/`, e.g. `data/timings.json` or `data/inputs/01.txt`.
+//! These belong to the year set by `AOC_YEAR` and are moved to `data/<year>/` once.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::Year;
+
+/// Folders of per-day files.
+const LEGACY_FOLDERS: [&str; 3] = ["examples", "inputs", "puzzles"];
+
+/// Prefixes of files that used to live directly in the data directory,
+/// e.g. `timings.json`, `timings.history.jsonl` or `benchmarks.<table>.svg`.
+const LEGACY_FILE_PREFIXES: [&str; 5] = [
+    "answers.",
+    "benchmarks.",
+    "dashboard.",
+    "submissions.",
+    "timings.",
+];
+
+/// Moves legacy files in `data/` to the data directory of `year`.
+/// Returns the paths of the moved files. Files that already exist in the year's directory are left in place.
+pub fn migrate(year: Year) -> io::Result<Vec<PathBuf>> {
+    migrate_dir(Path::new("data"), year)
+}
+
+fn migrate_dir(data_dir: &Path, year: Year) -> io::Result<Vec<PathBuf>> {
+    let year_dir = data_dir.join(year.to_string());
+    let mut moved = vec![];
+
+    for name in list_files(data_dir)? {
+        if LEGACY_FILE_PREFIXES.iter().any(|p| name.starts_with(p)) {
+            move_file(data_dir, &year_dir, &name, &mut moved)?;
+        }
+    }
+
+    for folder in LEGACY_FOLDERS {
+        let from = data_dir.join(folder);
+        let to = year_dir.join(folder);
+        for name in list_files(&from)? {
+            // NOTE: `.keep` files only exist to check in empty folders.
+            if !name.starts_with('.') {
+                move_file(&from, &to, &name, &mut moved)?;
+            }
+        }
+    }
+
+    Ok(moved)
+}
+
+/// Names of the files in `dir`, empty if it does not exist.
+fn list_files(dir: &Path) -> io::Result<Vec<String>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut names = vec![];
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            names.extend(entry.file_name().to_str().map(String::from));
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+fn move_file(from: &Path, to: &Path, name: &str, moved: &mut Vec<PathBuf>) -> io::Result<()> {
+    let target = to.join(name);
+    if target.exists() {
+        eprintln!(
+            "Not moving {:?}, as {target:?} already exists.",
+            from.join(name)
+        );
+        return Ok(());
+    }
+
+    fs::create_dir_all(to)?;
+    fs::rename(from.join(name), &target)?;
+    moved.push(target);
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::migrate_dir;
+    use crate::year;
+
+    #[test]
+    fn moves_legacy_files_to_the_year() {
+        let dir = env::temp_dir().join(format!("aoc-legacy-data-{}", process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+
+        write("timings.json", "{}");
+        write("answers.json", "old");
+        write("notes.md", "");
+        write("inputs/01.txt", "input");
+        write("inputs/.keep", "");
+        write("examples/01-2.txt", "example");
+        write("2024/answers.json", "new");
+
+        let moved = migrate_dir(&dir, year!(2024)).unwrap();
+        let read = |path: &str| fs::read_to_string(dir.join(path)).ok();
+
+        assert_eq!(moved.len(), 3);
+        assert_eq!(read("2024/timings.json").as_deref(), Some("{}"));
+        assert_eq!(read("2024/inputs/01.txt").as_deref(), Some("input"));
+        assert_eq!(read("2024/examples/01-2.txt").as_deref(), Some("example"));
+        assert_eq!(read("timings.json"), None);
+        // Existing files are not overwritten.
+        assert_eq!(read("answers.json").as_deref(), Some("old"));
+        assert_eq!(read("2024/answers.json").as_deref(), Some("new"));
+        // Unrelated files and `.keep` files stay in place.
+        assert!(dir.join("notes.md").exists());
+        assert!(dir.join("inputs/.keep").exists());
+
+        assert!(migrate_dir(&dir, year!(2024)).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
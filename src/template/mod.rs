@@ -1,68 +1,174 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod input;
+pub mod legacy_data;
+pub mod output;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
 pub mod runner;
 
 pub use day::*;
+pub use part::*;
+pub use solution::*;
+pub use year::*;
 
+mod answers;
+mod chart;
+mod compare;
+mod dashboard;
 mod day;
+mod examples;
+mod history;
+mod part;
 mod readme_benchmarks;
 mod run_multi;
+mod solution;
+mod stats;
+mod submissions;
+mod templates;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a puzzle to a string, e.g. `data/2024/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join(folder)
+        .join(format!("{}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year.to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY`, `YEAR` and `PUZZLE` and sets up the input and runner for each part.
+/// The year is taken from the file name of the solution, e.g. `src/bin/2024-01.rs`.
+/// The input is read from `data/<year>/inputs` unless `--input <path>`, `--input -` or `--example [N]` is passed.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The optional `parse = <fn>` parameter adds a separate parse stage. The function receives the
+/// raw input, is timed on its own, and its result is passed by reference to both parts:
+///
+/// ```ignore
+/// advent_of_code::solution!(2, parse = parse_input);
+///
+/// fn parse_input(input: &str) -> Vec<u32> { /* ... */ }
+/// pub fn part_one(input: &[u32]) -> Option<u32> { /* ... */ }
+/// pub fn part_two(input: &[u32]) -> Option<u32> { /* ... */ }
+/// ```
+///
+/// Instead of free functions, the macro can also run a type that implements [`Solution`]:
+///
+/// ```ignore
+/// advent_of_code::solution!(2, RedNosedReports);
+///
+/// struct RedNosedReports;
+///
+/// impl advent_of_code::template::Solution for RedNosedReports { /* ... */ }
+/// ```
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [], [part_one, $crate::template::Part::One] [part_two, $crate::template::Part::Two]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [], [part_one, $crate::template::Part::One]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [], [part_two, $crate::template::Part::Two]);
+    };
+    ($day:expr, parse = $parse:path) => {
+        $crate::solution!(@impl $day, [$parse], [part_one, $crate::template::Part::One] [part_two, $crate::template::Part::Two]);
+    };
+    ($day:expr, 1, parse = $parse:path) => {
+        $crate::solution!(@impl $day, [$parse], [part_one, $crate::template::Part::One]);
+    };
+    ($day:expr, 2, parse = $parse:path) => {
+        $crate::solution!(@impl $day, [$parse], [part_two, $crate::template::Part::Two]);
+    };
+    ($day:expr, $solution:ty) => {
+        $crate::solution!(
+            @impl $day,
+            [<$solution as $crate::template::Solution>::parse],
+            [<$solution as $crate::template::Solution>::part_one, $crate::template::Part::One]
+            [<$solution as $crate::template::Solution>::part_two, $crate::template::Part::Two]
+        );
+    };
+
+    (@impl $day:expr, [], $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            let input = read_input(PUZZLE);
+            let mut answers = [None, None];
+            $( run_part($func, &input, PUZZLE, $part, &mut answers); )*
+        }
+
+        #[doc(hidden)]
+        fn __solve(input: &str) -> Vec<$crate::template::output::PartRecord> {
+            use $crate::template::runner::*;
+            vec![$( solve_part($func, input, DAY, $part), )*]
+        }
     };
+    (@impl $day:expr, [$parse:expr], $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            let input = read_input(PUZZLE);
+            let parsed = run_parse($parse, &input, DAY);
+            let mut answers = [None, None];
+            $( run_part($func, &parsed, PUZZLE, $part, &mut answers); )*
+        }
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        #[doc(hidden)]
+        fn __solve(input: &str) -> Vec<$crate::template::output::PartRecord> {
+            use $crate::template::runner::*;
+            let (parsed, parse_record) = solve_parse($parse, input, DAY);
+            vec![parse_record, $( solve_part($func, &parsed, DAY, $part), )*]
+        }
+    };
+
+    (@common $day:expr) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The year of the current day.
+        const YEAR: $crate::template::Year = $crate::template::Year::__from_bin_path(file!());
+
+        /// The current puzzle, used to locate its input and example files.
+        const PUZZLE: $crate::template::PuzzleId = $crate::template::PuzzleId::new(YEAR, DAY);
+
+        /// The solution of the current day, as collected by the `all_days` binary.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution::new(PUZZLE, __solve);
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
-
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
-        }
     };
 }
@@ -0,0 +1,307 @@
+//! Structured output of solution binaries.
+//! In `--format json` mode, every stage of a solution prints one [`PartRecord`] per line.
+
+use std::{collections::HashMap, env, error::Error, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::stats::BenchStats;
+use crate::template::{Day, Part};
+
+/// How results are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Formatted for reading in a terminal.
+    #[default]
+    Human,
+    /// One JSON record per line.
+    Json,
+}
+
+impl OutputFormat {
+    /// Reads the `--format` flag passed to the current process.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        args.iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|x| x.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Human => f.write_str("human"),
+            Self::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either `human` or `json`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A stage of a solution: the optional parse step or one of the parts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => f.write_str("Parse"),
+            Self::Part(part) => write!(f, "Part {part}"),
+        }
+    }
+}
+
+/// Whether a stage produced a result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+}
+
+/// The result of running a single stage of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartRecord {
+    pub day: Day,
+    pub stage: Stage,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub stats: BenchStats,
+}
+
+impl PartRecord {
+    pub fn new(day: Day, stage: Stage, answer: Option<String>, stats: BenchStats) -> Self {
+        let status = if answer.is_some() || stage == Stage::Parse {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        };
+
+        Self {
+            day,
+            stage,
+            answer,
+            status,
+            stats,
+        }
+    }
+
+    /// Serializes the record to a single line of JSON.
+    pub fn to_json(&self) -> String {
+        // NOTE: serializing a JSON object built from strings and numbers does not fail.
+        JsonValue::from(self).stringify().unwrap()
+    }
+}
+
+impl From<&PartRecord> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part".into(),
+            match value.stage {
+                Stage::Parse => JsonValue::String("parse".into()),
+                Stage::Part(part) => JsonValue::Number(part.into_inner().into()),
+            },
+        );
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.stats.mean.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(
+                match value.status {
+                    Status::Solved => "solved",
+                    Status::Unsolved => "unsolved",
+                }
+                .into(),
+            ),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let stage = match json.get("part") {
+            Some(JsonValue::String(s)) if s == "parse" => Stage::Parse,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Some(JsonValue::Number(n)) => Part::new(*n as u8)
+                .map(Stage::Part)
+                .ok_or("Expected record.part to be 1, 2 or `parse`.")?,
+            _ => return Err("Expected record.part to be 1, 2 or `parse`.".into()),
+        };
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let status = match json.get("status").and_then(|v| v.get::<String>()) {
+            Some(s) if s == "solved" => Status::Solved,
+            Some(s) if s == "unsolved" => Status::Unsolved,
+            _ => return Err("Expected record.status to be `solved` or `unsolved`.".into()),
+        };
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected record.stats to be present.")
+            .map(BenchStats::try_from)??;
+
+        Ok(PartRecord {
+            day,
+            stage,
+            answer: answer.cloned(),
+            status,
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{OutputFormat, PartRecord, Stage, Status};
+    use crate::{
+        day,
+        template::{stats::BenchStats, Part},
+    };
+    use std::time::Duration;
+
+    fn get_mock_stats() -> BenchStats {
+        BenchStats::from_samples(&[Duration::from_nanos(70), Duration::from_nanos(80)]).unwrap()
+    }
+
+    #[test]
+    fn parses_output_format() {
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!(
+            "human".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Human
+        );
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn sets_status_from_answer() {
+        let solved = PartRecord::new(
+            day!(1),
+            Stage::Part(Part::One),
+            Some("1".into()),
+            get_mock_stats(),
+        );
+        let unsolved = PartRecord::new(day!(1), Stage::Part(Part::Two), None, get_mock_stats());
+        let parsed = PartRecord::new(day!(1), Stage::Parse, None, get_mock_stats());
+        assert_eq!(solved.status, Status::Solved);
+        assert_eq!(unsolved.status, Status::Unsolved);
+        assert_eq!(parsed.status, Status::Solved);
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let records = [
+            PartRecord::new(day!(3), Stage::Parse, None, get_mock_stats()),
+            PartRecord::new(
+                day!(3),
+                Stage::Part(Part::One),
+                Some("@ @ @ ( ) ms".into()),
+                get_mock_stats(),
+            ),
+            PartRecord::new(
+                day!(3),
+                Stage::Part(Part::Two),
+                Some("a\nb".into()),
+                get_mock_stats(),
+            ),
+            PartRecord::new(day!(3), Stage::Part(Part::Two), None, get_mock_stats()),
+        ];
+
+        for record in records {
+            let line = record.to_json();
+            assert!(!line.contains('\n'));
+            assert_eq!(PartRecord::try_from(line.as_str()).unwrap(), record);
+        }
+    }
+
+    #[test]
+    fn serializes_record_fields() {
+        let record = PartRecord::new(
+            day!(1),
+            Stage::Part(Part::One),
+            Some("42".into()),
+            get_mock_stats(),
+        );
+        let line = record.to_json();
+        assert!(line.contains(r#""day":"01""#));
+        assert!(line.contains(r#""part":1"#));
+        assert!(line.contains(r#""answer":"42""#));
+        assert!(line.contains(r#""duration_nanos":75"#));
+        assert!(line.contains(r#""samples":2"#));
+        assert!(line.contains(r#""status":"solved""#));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_text_output() {
+        PartRecord::try_from("Part 1: 0 (74.13ns @ 100000 samples)").unwrap();
+    }
+}
@@ -0,0 +1,344 @@
+//! Converts puzzle pages to Markdown and extracts their examples.
+//! Only handles the subset of html used on the Advent of Code website.
+
+use crate::template::aoc_client::DEFAULT_BASE_URL;
+use crate::template::Part;
+
+/// Puzzle page of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub html: String,
+}
+
+impl Puzzle {
+    /// Markdown of the puzzle description and of the answers that were already accepted.
+    pub fn to_markdown(&self) -> String {
+        let blocks = extract_elements(&self.html, "article").into_iter().chain(
+            extract_elements(&self.html, "p")
+                .into_iter()
+                .filter(|p| p.starts_with("<p>Your puzzle answer was")),
+        );
+
+        // NOTE: answers directly follow the article of their part, so restore document order.
+        let mut blocks: Vec<(usize, &str)> = blocks
+            .filter_map(|block| self.html.find(block).map(|i| (i, block)))
+            .collect();
+        blocks.sort_by_key(|(i, _)| *i);
+        blocks.dedup();
+
+        blocks
+            .iter()
+            .map(|(_, block)| to_markdown(block))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Example inputs: the first code block of each unlocked part, without duplicates.
+    pub fn examples(&self) -> Vec<String> {
+        let mut examples: Vec<String> = vec![];
+
+        for article in self.parts() {
+            let Some(block) = first_code_block(article) else {
+                continue;
+            };
+            if !examples.contains(&block) {
+                examples.push(block);
+            }
+        }
+
+        examples
+    }
+
+    /// Position of the example of a part in [`Puzzle::examples`].
+    pub fn example_index(&self, part: Part) -> Option<usize> {
+        let block = first_code_block(self.part(part)?)?;
+        self.examples().iter().position(|example| *example == block)
+    }
+
+    /// Expected answer for the example of a part: its last highlighted code, as in
+    /// "... for a total of <code><em>11</em></code>."
+    pub fn expected_answer(&self, part: Part) -> Option<String> {
+        highlighted_code(self.part(part)?).pop()
+    }
+
+    fn part(&self, part: Part) -> Option<&str> {
+        self.parts()
+            .into_iter()
+            .nth(usize::from(part.into_inner() - 1))
+    }
+
+    /// The description of each unlocked part, in order.
+    fn parts(&self) -> Vec<&str> {
+        extract_elements(&self.html, "article")
+    }
+}
+
+/// Extracts the title from a puzzle description, e.g. `Historian Hysteria`.
+pub fn puzzle_title(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        // NOTE: aoc-cli escapes markdown characters in headings, e.g. `\-\-\- Day 1: ... \-\-\-`.
+        let line = line.replace('\\', "");
+        let heading = line.trim_start_matches('#').trim();
+        let inner = heading.strip_prefix("---")?.strip_suffix("---")?;
+        let (_, title) = inner.split_once(':')?;
+        Some(title.trim().to_string())
+    })
+}
+
+/// Returns all elements with the given tag name, including the tags themselves. Nested elements of the same name are not supported.
+pub fn extract_elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut elements = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        // skip tags that only share a prefix, e.g. `<pre>` when looking for `<p>`.
+        let after = rest[start + open.len()..].chars().next();
+        if !matches!(after, Some('>' | ' ')) {
+            rest = &rest[start + open.len()..];
+            continue;
+        }
+
+        let Some(end) = rest[start..].find(&close) else {
+            break;
+        };
+        let end = start + end + close.len();
+        elements.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    elements
+}
+
+/// Text of an html fragment without any tags.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    for token in tokenize(html) {
+        if let Token::Text(t) = token {
+            text.push_str(t);
+        }
+    }
+    decode_entities(&text)
+}
+
+fn first_code_block(html: &str) -> Option<String> {
+    extract_elements(html, "pre").first().map(|b| strip_tags(b))
+}
+
+/// Contents of all `<code><em>` elements, which the puzzle uses to highlight results.
+fn highlighted_code(html: &str) -> Vec<String> {
+    html.match_indices("<code><em>")
+        .filter_map(|(i, tag)| {
+            let start = i + tag.len();
+            let end = start + html[start..].find("</em></code>")?;
+            Some(strip_tags(&html[start..end]))
+        })
+        .collect()
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    /// Name, attributes and whether it is a closing tag.
+    Tag(&'a str, &'a str, bool),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = rest[start + 1..start + end].trim_end_matches('/');
+        let is_closing = tag.starts_with('/');
+        let tag = tag.trim_start_matches('/');
+        let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+        tokens.push(Token::Tag(name, attributes, is_closing));
+
+        rest = &rest[start + end + 1..];
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+
+    tokens
+}
+
+/// Converts an html fragment to Markdown.
+fn to_markdown(html: &str) -> String {
+    // NOTE: emphasis can not be nested into inline code, so move it outside.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(&html) {
+        match token {
+            Token::Text(text) => markdown.push_str(&decode_entities(text)),
+            Token::Tag("pre", _, false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::Tag("pre", _, true) => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            // inside code blocks, only the text is kept.
+            Token::Tag(..) if in_pre => {}
+            Token::Tag("code", _, _) => markdown.push('`'),
+            Token::Tag("em", _, _) => markdown.push('*'),
+            Token::Tag("h2", _, false) => markdown.push_str("## "),
+            Token::Tag("h2" | "p", _, true) => markdown.push_str("\n\n"),
+            Token::Tag("ul", _, true) => markdown.push('\n'),
+            Token::Tag("li", _, false) => markdown.push_str("- "),
+            Token::Tag("li", _, true) => markdown.push('\n'),
+            Token::Tag("a", attributes, false) => {
+                links.push(attribute(attributes, "href").map_or_else(String::new, absolute_url));
+                markdown.push('[');
+            }
+            Token::Tag("a", _, true) => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            Token::Tag(..) => {}
+        }
+    }
+
+    let mut markdown = markdown.trim().to_string();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + attributes[start..].find('"')?;
+    Some(&attributes[start..end])
+}
+
+fn absolute_url(href: &str) -> String {
+    if href.starts_with('/') {
+        format!("{DEFAULT_BASE_URL}{href}")
+    } else {
+        href.into()
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{puzzle_title, Puzzle};
+    use crate::template::Part;
+
+    fn get_mock_puzzle() -> Puzzle {
+        Puzzle {
+            html: [
+                "<main>",
+                "<article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2><p>Pair up the <em>smallest</em> numbers &amp; check that <code>x &lt; y</code>. See <a href=\"/2024/about\">about</a>.</p>",
+                "<p>For example:</p>",
+                "<pre><code>3   4\n4   3\n<em>2</em>   5\n</code></pre>",
+                "<ul><li>The first pair is <code>(1, 3)</code>.</li><li>The second pair.</li></ul>",
+                "<p>In the example above, this is <code>2 + 1</code>, a total of <code><em>11</em></code>.</p>",
+                "</article>",
+                "<p>Your puzzle answer was <code>1830467</code>.</p>",
+                "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>",
+                "<pre><code>3   4\n4   3\n2   5\n</code></pre>",
+                "<pre><code>1   2\n</code></pre>",
+                "<p>So, the similarity score is <code><em>31</em></code>.</p>",
+                "</article>",
+                "<p class=\"day-success\">Both parts of this puzzle are complete!</p>",
+                "<form><input/></form></main>",
+            ]
+            .join("\n"),
+        }
+    }
+
+    #[test]
+    fn converts_to_markdown() {
+        let markdown = get_mock_puzzle().to_markdown();
+        let expected = [
+            "## --- Day 1: Historian Hysteria ---",
+            "",
+            "Pair up the *smallest* numbers & check that `x < y`. See [about](https://adventofcode.com/2024/about).",
+            "",
+            "For example:",
+            "",
+            "```",
+            "3   4",
+            "4   3",
+            "2   5",
+            "```",
+            "",
+            "- The first pair is `(1, 3)`.",
+            "- The second pair.",
+            "",
+            "In the example above, this is `2 + 1`, a total of *`11`*.",
+            "",
+            "Your puzzle answer was `1830467`.",
+            "",
+            "## --- Part Two ---",
+        ]
+        .join("\n");
+
+        assert!(markdown.starts_with(&expected), "{markdown}");
+        assert!(markdown.ends_with("So, the similarity score is *`31`*."));
+        assert!(!markdown.contains("Both parts"));
+    }
+
+    #[test]
+    fn extracts_titles() {
+        let markdown = get_mock_puzzle().to_markdown();
+        assert_eq!(puzzle_title(&markdown), Some("Historian Hysteria".into()));
+        assert_eq!(
+            puzzle_title("\\-\\-\\- Day 8: Resonant Collinearity \\-\\-\\-"),
+            Some("Resonant Collinearity".into())
+        );
+        assert_eq!(puzzle_title("no title"), None);
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let puzzle = get_mock_puzzle();
+        assert_eq!(puzzle.examples(), ["3   4\n4   3\n2   5\n"]);
+        assert_eq!(puzzle.example_index(Part::One), Some(0));
+        assert_eq!(puzzle.example_index(Part::Two), Some(0));
+        assert_eq!(puzzle.expected_answer(Part::One), Some("11".into()));
+        assert_eq!(puzzle.expected_answer(Part::Two), Some("31".into()));
+    }
+
+    #[test]
+    fn handles_locked_parts() {
+        let puzzle = Puzzle {
+            html: "<article><p>Nothing to see.</p></article>".into(),
+        };
+        assert!(puzzle.examples().is_empty());
+        assert_eq!(puzzle.example_index(Part::One), None);
+        assert_eq!(puzzle.expected_answer(Part::One), None);
+        assert_eq!(puzzle.expected_answer(Part::Two), None);
+        assert_eq!(puzzle.to_markdown(), "Nothing to see.");
+    }
+}
@@ -1,12 +1,33 @@
-/// Module that updates the readme me with timing information.
-/// The approach taken is similar to how `aoc-readme-stars` handles this.
+//! Module that updates the readme me with timing information.
+//! The approach taken is similar to how `aoc-readme-stars` handles this.
+
 use std::{fs, io};
 
+use crate::template::chart::render_svg;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
+/// Chart of the timings in the data directory of a year, linked from the benchmarking table.
+static CHART_FILE_NAME: &str = "benchmarks.svg";
+
+/// Marker of a benchmarking table. Named tables use e.g. `<!--- benchmarking table:laptop --->`.
+fn marker(table: Option<&str>) -> String {
+    table.map_or_else(
+        || MARKER.into(),
+        |name| format!("<!--- benchmarking table:{name} --->"),
+    )
+}
+
+/// Chart of a benchmarking table. Named tables use e.g. `data/2024/benchmarks.laptop.svg`.
+fn chart_path(year: Year, table: Option<&str>) -> String {
+    table.map_or_else(
+        || year.data_path(CHART_FILE_NAME),
+        |name| year.data_path(&format!("benchmarks.{name}.svg")),
+    )
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -26,101 +47,138 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./{}", puzzle.bin_path())
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+        return Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        )));
     }
 
     let pos_start = matches
         .first()
         .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("{marker}: could not find table start position.")))?;
 
     let pos_end = matches
         .last()
         .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+        .ok_or_else(|| Error::Parser(format!("{marker}: could not find table end position.")))?;
 
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    table: Option<&str>,
+) -> String {
+    let header = table.map_or_else(
+        || format!("{prefix} Benchmarks"),
+        |name| format!("{prefix} Benchmarks ({name})"),
+    );
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(table),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("![Benchmark chart](./{})", chart_path(year, table)),
+        String::new(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(PuzzleId::new(year, timing.day));
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.parse.map_or_else(|| "-".into(), |t| t.to_string()),
+            timing.part_1.map_or_else(|| "-".into(), |t| t.to_string()),
+            timing.part_2.map_or_else(|| "-".into(), |t| t.to_string())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(table));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    table: Option<&str>,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &marker(table))?;
+    let content = construct_table("##", year, timings, total_millis, table);
+    s.replace_range(positions.pos_start..positions.pos_end, &content);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Updates the benchmarking table with the given name, or the default table if `table` is [`None`].
+/// Other tables are left untouched.
+pub fn update(year: Year, timings: Timings, table: Option<&str>) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let timings = timings.in_year(year);
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings.clone(), total_millis, table)?;
+    fs::write(chart_path(year, table), render_svg(&timings))?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{marker, update_content, MARKER};
+    use crate::{
+        day,
+        template::timings::{StageTiming, Timing, Timings},
+        year,
+    };
+
+    fn stage(nanos: f64) -> Option<StageTiming> {
+        Some(StageTiming {
+            nanos,
+            samples: 1,
+            stats: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: stage(10e6),
+                    part_2: stage(20e6),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: stage(5e6),
+                    part_1: stage(30e6),
+                    part_2: stage(40e6),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: None,
+                    part_1: stage(40e6),
+                    part_2: stage(50e6),
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,28 +189,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,18 +218,20 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "![Benchmark chart](./data/2024/benchmarks.svg)",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024-01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `-` | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +240,42 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn updates_named_benchmarks() {
+        let laptop = marker(Some("laptop"));
+        let mut s = format!("foo\n{MARKER}\n{MARKER}\n{laptop}\n{laptop}\nbaz");
+        update_content(
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+            Some("laptop"),
+        )
+        .unwrap();
+
+        assert_eq!(s.matches(&laptop).count(), 2);
+        assert!(s.contains(&format!("{MARKER}\n{MARKER}\n")));
+        assert!(s.contains("## Benchmarks (laptop)"));
+        assert!(s.contains("![Benchmark chart](./data/2024/benchmarks.laptop.svg)"));
+
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, None).unwrap();
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches("## Benchmarks").count(), 2);
+        assert_eq!(s.matches("## Benchmarks (laptop)").count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_named_marker_not_present() {
+        let mut s = format!("foo\n{MARKER}\n{MARKER}\nbaz");
+        update_content(
+            &mut s,
+            year!(2024),
+            get_mock_timings(),
+            190.0,
+            Some("desktop"),
+        )
+        .unwrap();
+    }
 }
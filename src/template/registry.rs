@@ -0,0 +1,309 @@
+//! Registry of solutions that can be run in-process.
+//! Every `solution!` invocation creates a [`Solution`], and the `all_days` binary collects them into a [`Registry`].
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, Write},
+    panic,
+    process::{Command, Output},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use crate::template::output::{OutputFormat, PartRecord};
+use crate::template::runner::print_record;
+use crate::template::{Day, PuzzleId, Year};
+
+/// A solution for a single puzzle that runs all of its stages on an input.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub id: PuzzleId,
+    pub run: fn(&str) -> Vec<PartRecord>,
+}
+
+impl Solution {
+    pub const fn new(id: PuzzleId, run: fn(&str) -> Vec<PartRecord>) -> Self {
+        Self { id, run }
+    }
+}
+
+/// A set of solutions, ordered by year and day.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<PuzzleId, Solution>,
+}
+
+impl Registry {
+    /// Adds a solution, replacing a previously registered one for the same puzzle.
+    pub fn register(&mut self, solution: Solution) {
+        self.solutions.insert(solution.id, solution);
+    }
+
+    pub fn get(&self, id: PuzzleId) -> Option<&Solution> {
+        self.solutions.get(&id)
+    }
+
+    /// Iterates over the registered solutions, ordered by year and day.
+    pub fn iter(&self) -> impl Iterator<Item = &Solution> {
+        self.solutions.values()
+    }
+
+    /// Runs a puzzle against its input. Returns [`None`] if the puzzle is not registered,
+    /// its input cannot be read or the solution panics.
+    pub fn run(&self, id: PuzzleId) -> Option<Vec<PartRecord>> {
+        let solution = self.get(id)?;
+        let path = id.data_path("inputs");
+
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Could not open input file {path}: {e}");
+                return None;
+            }
+        };
+
+        // NOTE: a panicking solution should not prevent the remaining days from running.
+        // the panic message itself is printed by the default hook.
+        panic::catch_unwind(|| (solution.run)(&input)).ok()
+    }
+}
+
+/// Calls `run` for the given days on up to `jobs` threads and `on_result` for each of them in the order of `days`.
+/// Results that finish early are buffered until all previous days are done.
+pub fn run_in_order<R: Send>(
+    days: &[Day],
+    jobs: usize,
+    run: impl Fn(Day) -> R + Sync,
+    mut on_result: impl FnMut(Day, R),
+) {
+    if jobs <= 1 {
+        days.iter().for_each(|&day| on_result(day, run(day)));
+        return;
+    }
+
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next_index = &next_index;
+            let run = &run;
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                if sender.send((index, run(day))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        // NOTE: drop the original sender so the receiver stops once all workers are done.
+        drop(sender);
+
+        let mut buffered = BTreeMap::new();
+        let mut next_to_print = 0;
+
+        for (index, result) in receiver {
+            buffered.insert(index, result);
+            while let Some(result) = buffered.remove(&next_to_print) {
+                on_result(days[next_to_print], result);
+                next_to_print += 1;
+            }
+        }
+    });
+}
+
+/// Runs a single day in a child process of the current binary and captures everything it prints,
+/// including output of the solution itself and panic messages.
+fn run_captured(year: Year, day: Day, format: OutputFormat) -> io::Result<Output> {
+    let mut args = vec![
+        "--format".to_string(),
+        format.to_string(),
+        "--year".to_string(),
+        year.to_string(),
+    ];
+
+    if env::args().any(|x| x == "--time") {
+        args.push("--time".to_string());
+    }
+
+    args.push(day.to_string());
+
+    Command::new(env::current_exe()?).args(&args).output()
+}
+
+/// Entry point of the `all_days` binary.
+///
+/// Runs the days passed as free arguments (or all registered days) of the year given by `--year`
+/// (or `AOC_YEAR`) and prints their records, honouring the same `--format` and `--time` flags as
+/// the solution binaries.
+/// With `--jobs <n>`, up to `n` days are solved concurrently, each in a child process. All output
+/// of a day is buffered and printed in day order, its stderr following its stdout on stdout.
+pub fn run_main(registry: &Registry) {
+    let format = OutputFormat::from_args();
+
+    let mut args = pico_args::Arguments::from_env();
+    // NOTE: flags are read by the runner, they only need to be removed from the free arguments.
+    let _ = args.contains("--time");
+    let _ = args.opt_value_from_str::<_, String>("--format");
+
+    let jobs: usize = match args.opt_value_from_str("--jobs") {
+        Ok(jobs) => jobs.unwrap_or(1),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    let year: Year = match args.opt_value_from_str("--year") {
+        Ok(year) => year.or_else(Year::from_env).unwrap_or_else(|| {
+            eprintln!("Error: no year given. Pass `--year` or set AOC_YEAR.");
+            std::process::exit(1);
+        }),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    let days: Vec<Day> = match args
+        .finish()
+        .iter()
+        .map(|arg| arg.to_string_lossy().parse())
+        .collect::<Result<Vec<Day>, _>>()
+    {
+        Ok(days) if days.is_empty() => registry
+            .iter()
+            .filter(|s| s.id.year == year)
+            .map(|s| s.id.day)
+            .collect(),
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    // NOTE: days that run on the main thread print in order already.
+    if jobs <= 1 {
+        for day in days {
+            for record in registry.run(PuzzleId::new(year, day)).unwrap_or_default() {
+                print_record(&record, format);
+            }
+        }
+        return;
+    }
+
+    run_in_order(
+        &days,
+        jobs,
+        |day| run_captured(year, day, format),
+        |day, output| match output {
+            Ok(output) => {
+                // NOTE: stderr goes to stdout as well, so it stays in order with the records.
+                let mut stdout = io::stdout().lock();
+                let _ = stdout.write_all(&output.stdout);
+                let _ = stdout.write_all(&output.stderr);
+                let _ = stdout.flush();
+            }
+            Err(e) => eprintln!("Could not run day {day}: {e}"),
+        },
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run_in_order, Registry, Solution};
+    use std::{thread, time::Duration};
+
+    use crate::{
+        day,
+        template::{
+            output::{PartRecord, Stage},
+            runner::solve_part,
+            Part, PuzzleId,
+        },
+        year,
+    };
+
+    const DAY_2: PuzzleId = PuzzleId::new(year!(2024), day!(2));
+    const DAY_5: PuzzleId = PuzzleId::new(year!(2024), day!(5));
+    const PAST_DAY_2: PuzzleId = PuzzleId::new(year!(2023), day!(2));
+
+    fn run_mock(input: &str) -> Vec<PartRecord> {
+        vec![solve_part(
+            |input: &str| Some(input.len()),
+            input,
+            day!(2),
+            Part::One,
+        )]
+    }
+
+    #[test]
+    fn registers_solutions_in_order() {
+        let mut registry = Registry::default();
+        registry.register(Solution::new(DAY_5, run_mock));
+        registry.register(Solution::new(DAY_2, run_mock));
+        registry.register(Solution::new(PAST_DAY_2, run_mock));
+        registry.register(Solution::new(DAY_5, run_mock));
+
+        let ids: Vec<_> = registry.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![PAST_DAY_2, DAY_2, DAY_5]);
+        assert!(registry.get(PuzzleId::new(year!(2024), day!(3))).is_none());
+    }
+
+    #[test]
+    fn runs_solutions() {
+        let mut registry = Registry::default();
+        registry.register(Solution::new(DAY_2, run_mock));
+
+        let records = (registry.get(DAY_2).unwrap().run)("abc");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].stage, Stage::Part(Part::One));
+        assert_eq!(records[0].answer, Some("3".into()));
+    }
+
+    #[test]
+    fn runs_days_in_order() {
+        let days = [day!(1), day!(2), day!(3), day!(4), day!(5)];
+
+        for jobs in [1, 3] {
+            let mut seen = vec![];
+            run_in_order(
+                &days,
+                jobs,
+                |day| {
+                    // make earlier days finish last.
+                    thread::sleep(Duration::from_millis(u64::from(10 - day.into_inner()) * 5));
+                    day.into_inner() * 2
+                },
+                |day, result| seen.push((day, result)),
+            );
+            assert_eq!(
+                seen,
+                vec![
+                    (day!(1), 2),
+                    (day!(2), 4),
+                    (day!(3), 6),
+                    (day!(4), 8),
+                    (day!(5), 10)
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn skips_unregistered_days() {
+        let registry = Registry::default();
+        assert!(registry.run(DAY_2).is_none());
+    }
+}
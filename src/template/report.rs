@@ -0,0 +1,414 @@
+//! Standalone reports of stored timings, for use outside of the readme.
+
+use std::{collections::HashMap, error::Error, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::timings::{StageTiming, Timing, Timings};
+
+/// File format of a report.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    Csv,
+    Json,
+}
+
+impl ReportFormat {
+    /// Guesses the format from the extension of an output path.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        match extension {
+            "md" => Some(Self::Markdown),
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(ReportFromStrError(
+                "expecting a report format of `markdown`, `csv` or `json`",
+            )),
+        }
+    }
+}
+
+/// A column of a report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Day,
+    Parse,
+    Part1,
+    Part2,
+    ParseSamples,
+    Part1Samples,
+    Part2Samples,
+    Total,
+    /// Share of the day in the total of all days.
+    Share,
+}
+
+impl Column {
+    pub const DEFAULT: [Column; 6] = [
+        Column::Day,
+        Column::Parse,
+        Column::Part1,
+        Column::Part2,
+        Column::Total,
+        Column::Share,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Parse => "parse",
+            Self::Part1 => "part_1",
+            Self::Part2 => "part_2",
+            Self::ParseSamples => "parse_samples",
+            Self::Part1Samples => "part_1_samples",
+            Self::Part2Samples => "part_2_samples",
+            Self::Total => "total",
+            Self::Share => "share",
+        }
+    }
+
+    /// Key of the column in machine-readable reports, including the unit of its values.
+    fn key(self) -> String {
+        match self {
+            Self::Parse | Self::Part1 | Self::Part2 | Self::Total => {
+                format!("{}_nanos", self.name())
+            }
+            Self::Share => "share_pct".into(),
+            _ => self.name().into(),
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Day => "Day",
+            Self::Parse => "Parse",
+            Self::Part1 => "Part 1",
+            Self::Part2 => "Part 2",
+            Self::ParseSamples => "Parse samples",
+            Self::Part1Samples => "Part 1 samples",
+            Self::Part2Samples => "Part 2 samples",
+            Self::Total => "Total",
+            Self::Share => "Share",
+        }
+    }
+
+    /// Parses a comma-separated list of column names, e.g. `day,part_1,share`.
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, ReportFromStrError> {
+        s.split(',').map(|name| name.trim().parse()).collect()
+    }
+}
+
+impl FromStr for Column {
+    type Err = ReportFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Self::Day,
+            Self::Parse,
+            Self::Part1,
+            Self::Part2,
+            Self::ParseSamples,
+            Self::Part1Samples,
+            Self::Part2Samples,
+            Self::Total,
+            Self::Share,
+        ]
+        .into_iter()
+        .find(|column| column.name() == s)
+        .ok_or(ReportFromStrError(
+            "expecting columns out of `day`, `parse`, `part_1`, `part_2`, `parse_samples`, `part_1_samples`, `part_2_samples`, `total` and `share`",
+        ))
+    }
+}
+
+/// An error which can be returned when parsing a [`ReportFormat`] or [`Column`].
+#[derive(Debug)]
+pub struct ReportFromStrError(&'static str);
+
+impl Error for ReportFromStrError {}
+
+impl Display for ReportFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single cell of a report, kept numeric until rendered.
+enum Cell {
+    Text(String),
+    Nanos(Option<f64>),
+    Count(Option<u128>),
+    Percent(f64),
+}
+
+fn cell(timing: &Timing, column: Column, total_nanos: f64) -> Cell {
+    let nanos = |stage: Option<StageTiming>| Cell::Nanos(stage.map(|s| s.nanos));
+    let samples = |stage: Option<StageTiming>| Cell::Count(stage.map(|s| s.samples));
+
+    match column {
+        Column::Day => Cell::Text(timing.day.to_string()),
+        Column::Parse => nanos(timing.parse),
+        Column::Part1 => nanos(timing.part_1),
+        Column::Part2 => nanos(timing.part_2),
+        Column::ParseSamples => samples(timing.parse),
+        Column::Part1Samples => samples(timing.part_1),
+        Column::Part2Samples => samples(timing.part_2),
+        Column::Total => Cell::Nanos(Some(timing.total_nanos)),
+        Column::Share if total_nanos > 0.0 => {
+            Cell::Percent(timing.total_nanos / total_nanos * 100.0)
+        }
+        Column::Share => Cell::Percent(0.0),
+    }
+}
+
+/// Renders timings as a report with the given columns.
+pub fn render(timings: &Timings, format: ReportFormat, columns: &[Column]) -> String {
+    let total_nanos: f64 = timings.data.iter().map(|t| t.total_nanos).sum();
+    let rows: Vec<Vec<Cell>> = timings
+        .data
+        .iter()
+        .map(|timing| {
+            columns
+                .iter()
+                .map(|&column| cell(timing, column, total_nanos))
+                .collect()
+        })
+        .collect();
+
+    match format {
+        ReportFormat::Markdown => render_markdown(&rows, columns, total_nanos),
+        ReportFormat::Csv => render_csv(&rows, columns),
+        ReportFormat::Json => render_json(&rows, columns, total_nanos),
+    }
+}
+
+fn render_markdown(rows: &[Vec<Cell>], columns: &[Column], total_nanos: f64) -> String {
+    let format_cell = |cell: &Cell| match cell {
+        Cell::Text(s) => s.clone(),
+        Cell::Nanos(Some(n)) => format!("`{}`", format_nanos(*n)),
+        Cell::Count(Some(n)) => n.to_string(),
+        Cell::Nanos(None) | Cell::Count(None) => "-".into(),
+        Cell::Percent(p) => format!("{p:.1}%"),
+    };
+
+    let mut lines = vec![
+        format!(
+            "| {} |",
+            columns
+                .iter()
+                .map(|c| c.title())
+                .collect::<Vec<_>>()
+                .join(" | ")
+        ),
+        format!("|{}", " :---: |".repeat(columns.len())),
+    ];
+
+    for row in rows {
+        lines.push(format!(
+            "| {} |",
+            row.iter().map(format_cell).collect::<Vec<_>>().join(" | ")
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {}**", format_nanos(total_nanos)));
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn render_csv(rows: &[Vec<Cell>], columns: &[Column]) -> String {
+    // NOTE: durations are written as plain nanoseconds so spreadsheets can compute with them.
+    let format_cell = |cell: &Cell| match cell {
+        Cell::Text(s) => s.clone(),
+        Cell::Nanos(Some(n)) => format!("{n:.0}"),
+        Cell::Count(Some(n)) => n.to_string(),
+        Cell::Nanos(None) | Cell::Count(None) => String::new(),
+        Cell::Percent(p) => format!("{p:.2}"),
+    };
+
+    let header = columns
+        .iter()
+        .map(|c| c.key())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut lines = vec![header];
+    for row in rows {
+        lines.push(row.iter().map(format_cell).collect::<Vec<_>>().join(","));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn render_json(rows: &[Vec<Cell>], columns: &[Column], total_nanos: f64) -> String {
+    let to_json = |cell: &Cell| match cell {
+        Cell::Text(s) => JsonValue::String(s.clone()),
+        Cell::Nanos(n) => n.map_or(JsonValue::Null, JsonValue::Number),
+        Cell::Count(n) => n.map_or(JsonValue::Null, |n| JsonValue::Number(n as f64)),
+        Cell::Percent(p) => JsonValue::Number(*p),
+    };
+
+    let data = rows
+        .iter()
+        .map(|row| {
+            let map: HashMap<String, JsonValue> = columns
+                .iter()
+                .zip(row)
+                .map(|(column, cell)| (column.key(), to_json(cell)))
+                .collect();
+            JsonValue::Object(map)
+        })
+        .collect();
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("total_nanos".into(), JsonValue::Number(total_nanos));
+    map.insert("data".into(), JsonValue::Array(data));
+
+    // NOTE: formatting a JSON object built from strings and numbers does not fail.
+    let mut json = JsonValue::Object(map).format().unwrap();
+    json.push('\n');
+    json
+}
+
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = std::time::Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, Column, ReportFormat};
+    use crate::{
+        day,
+        template::timings::{StageTiming, Timing, Timings},
+    };
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    fn stage(nanos: f64, samples: u128) -> Option<StageTiming> {
+        Some(StageTiming {
+            nanos,
+            samples,
+            stats: None,
+        })
+    }
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: stage(1e6, 100),
+                    part_2: stage(2e6, 50),
+                    total_nanos: 3e6,
+                },
+                Timing {
+                    day: day!(2),
+                    parse: stage(500.0, 10),
+                    part_1: stage(500.0, 10),
+                    part_2: None,
+                    total_nanos: 1e3,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn parses_columns_and_formats() {
+        assert_eq!(
+            Column::parse_list("day, part_1,share").unwrap(),
+            vec![Column::Day, Column::Part1, Column::Share]
+        );
+        assert!(Column::parse_list("day,speed").is_err());
+        assert_eq!(
+            ReportFormat::from_str("md").unwrap(),
+            ReportFormat::Markdown
+        );
+        assert_eq!(
+            ReportFormat::from_path("out/report.csv"),
+            Some(ReportFormat::Csv)
+        );
+        assert_eq!(ReportFormat::from_path("report"), None);
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let report = render(
+            &get_mock_timings(),
+            ReportFormat::Markdown,
+            &Column::DEFAULT,
+        );
+        let expected = [
+            "| Day | Parse | Part 1 | Part 2 | Total | Share |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: |",
+            "| 01 | - | `1.0ms` | `2.0ms` | `3.0ms` | 100.0% |",
+            "| 02 | `500.0ns` | `500.0ns` | - | `1.0µs` | 0.0% |",
+            "",
+            "**Total: 3.0ms**",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn renders_csv() {
+        let report = render(
+            &get_mock_timings(),
+            ReportFormat::Csv,
+            &[
+                Column::Day,
+                Column::Part2,
+                Column::Part2Samples,
+                Column::Share,
+            ],
+        );
+        let expected = [
+            "day,part_2_nanos,part_2_samples,share_pct",
+            "01,2000000,50,99.97",
+            "02,,,0.03",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn renders_json() {
+        let report = render(
+            &get_mock_timings(),
+            ReportFormat::Json,
+            &[Column::Day, Column::Parse, Column::Total],
+        );
+        let json: JsonValue = report.parse().unwrap();
+        let rows: &Vec<JsonValue> = json["data"].get().unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["day"], JsonValue::String("01".into()));
+        assert_eq!(rows[0]["parse_nanos"], JsonValue::Null);
+        assert_eq!(rows[1]["parse_nanos"], JsonValue::Number(500.0));
+        assert_eq!(rows[1]["total_nanos"], JsonValue::Number(1e3));
+        assert_eq!(json["total_nanos"], JsonValue::Number(3_001_000.0));
+    }
+}
@@ -1,21 +1,33 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    io,
+    process::{self, ExitStatus},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use crate::template::{
+    output::{OutputFormat, PartRecord},
+    runner::{print_record, BenchConfig},
+    Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
+use super::timings::{Timing, Timings};
+
+/// Runs the given days, benching them with `bench` if present.
+/// Up to `jobs` days are solved concurrently, output is still printed in day order.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    bench: Option<&BenchConfig>,
+    format: OutputFormat,
+    jobs: usize,
+) -> Option<Timings> {
+    let is_human = format == OutputFormat::Human;
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
+    let mut print_header = |day: Day| {
+        if is_human {
             if need_space {
                 println!();
             }
@@ -23,34 +35,84 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
+        }
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let print_not_solved = || {
+        if is_human {
+            println!("Not solved.");
+        }
+    };
+
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = year
+        .days()
+        .filter(|day| days_to_run.contains(day))
+        .collect();
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+    let mut pending = days.iter().copied().peekable();
+    let mut records: BTreeMap<Day, Vec<PartRecord>> = BTreeMap::new();
+
+    let result = child_commands::run_solutions(year, &days, bench, is_release, jobs, |record| {
+        if !records.contains_key(&record.day) {
+            // days without records were skipped by the runner, e.g. because they are not scaffolded.
+            while let Some(day) = pending.next_if(|&day| day < record.day) {
+                print_header(day);
+                print_not_solved();
             }
-        });
+            pending.next_if_eq(&record.day);
+            print_header(record.day);
+        }
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        print_record(&record, format);
+        records.entry(record.day).or_default().push(record);
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to run solutions: {e}");
+        process::exit(1);
+    }
+
+    for day in pending {
+        print_header(day);
+        print_not_solved();
+    }
+
+    if bench.is_some() {
+        let timings = Timings {
+            data: records
+                .iter()
+                .map(|(&day, records)| child_commands::timing_from_records(records, day))
+                .collect(),
+        };
+        if is_human {
+            let total_millis = timings.total_millis();
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    /// The `all_days` binary failed to build or exited with an error.
+    Failed(ExitStatus),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read the output of the solutions"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Failed(status) => write!(f, "the runner exited with {status}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -59,48 +121,70 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// Solutions are run in-process by the `all_days` binary, which collects every day in `src/bin`.
+/// This module encapsulates interaction with this binary, both invoking it as well as reading the records it outputs.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{
+        output::{PartRecord, Stage, Status},
+        runner::BenchConfig,
+        timings::StageTiming,
+        Day, Part, Year,
+    };
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Run the solutions for the given days of a year, calling `on_record` for every record as it arrives.
+    pub fn run_solutions(
+        year: Year,
+        days: &[Day],
+        bench: Option<&BenchConfig>,
+        is_release: bool,
+        jobs: usize,
+        mut on_record: impl FnMut(PartRecord),
+    ) -> Result<(), Error> {
+        if days.is_empty() {
+            return Ok(());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            "all_days".into(),
+        ];
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
         }
 
-        if is_timed {
+        // the runner always outputs records, which are rendered by the caller in the requested format.
+        args.extend([
+            "--".into(),
+            "--format".into(),
+            "json".into(),
+            "--jobs".into(),
+            jobs.to_string(),
+            "--year".into(),
+            year.to_string(),
+        ]);
+
+        if bench.is_some() {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            args.push("--time".into());
         }
 
+        args.extend(days.iter().map(Day::to_string));
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while grabbing records.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
+            .envs(bench.map(BenchConfig::to_env).unwrap_or_default())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,8 +192,6 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
@@ -118,75 +200,53 @@ pub mod child_commands {
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            match PartRecord::try_from(line.as_str()) {
+                Ok(record) => on_record(record),
+                // output that solutions print themselves. stdout is kept clean for records.
+                Err(_) => eprintln!("{line}"),
+            }
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
 
-        Ok(output)
+        let status = cmd.wait()?;
+        if !status.success() {
+            return Err(Error::Failed(status));
+        }
+
+        Ok(())
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
+    /// Collect the records of a single day into a [`super::Timing`].
+    /// Parts without an answer are left out, so they do not count as benched.
+    pub fn timing_from_records(records: &[PartRecord], day: Day) -> super::Timing {
+        let mut timing = super::Timing {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
         };
 
-        output
+        for record in records
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
-
-        timings
-    }
+            .filter(|r| r.day == day && r.status == Status::Solved)
+        {
+            let stage = Some(StageTiming::from_stats(record.stats));
+
+            match record.stage {
+                Stage::Parse => timing.parse = stage,
+                Stage::Part(Part::One) => timing.part_1 = stage,
+                Stage::Part(Part::Two) => timing.part_2 = stage,
+            }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+            #[allow(clippy::cast_precision_loss)]
+            {
+                timing.total_nanos += record.stats.mean.as_nanos() as f64;
+            }
+        }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        timing
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,50 +265,99 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::timing_from_records;
+        use std::time::Duration;
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{
+                output::{PartRecord, Stage},
+                stats::BenchStats,
+                Part,
+            },
+        };
+
+        fn record(stage: Stage, answer: Option<&str>, samples: &[u64]) -> PartRecord {
+            let samples: Vec<Duration> = samples.iter().map(|&x| Duration::from_nanos(x)).collect();
+            PartRecord::new(
+                day!(1),
+                stage,
+                answer.map(Into::into),
+                BenchStats::from_samples(&samples).unwrap(),
+            )
+        }
+
+        #[test]
+        fn collects_execution_times() {
+            let res = timing_from_records(
+                &[
+                    record(Stage::Part(Part::One), Some("0"), &[74, 74]),
+                    record(Stage::Part(Part::Two), Some("10"), &[74_130_000]),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap().to_string(), "74.0ns");
+            assert_eq!(res.part_2.unwrap().to_string(), "74.1ms");
+            assert_eq!(res.part_1.unwrap().samples, 2);
+            assert_eq!(res.part_2.unwrap().samples, 1);
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_parse_stage() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(Stage::Parse, None, &[1_000_000, 2_000_000]),
+                    record(Stage::Part(Part::One), Some("0"), &[100]),
+                    record(Stage::Part(Part::Two), Some("10"), &[100]),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 1500200_f64);
+            assert_eq!(res.parse.unwrap().to_string(), "1.5ms");
+            assert_eq!(
+                res.parse.unwrap().stats.unwrap().max,
+                Duration::from_millis(2)
+            );
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answer() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(
+                        Stage::Part(Part::One),
+                        Some("@ @ @ ( ) ms"),
+                        &[2_000_000_000],
+                    ),
+                    record(Stage::Part(Part::Two), Some("10s"), &[100_000_000]),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap().to_string(), "2.0s");
+            assert_eq!(res.part_2.unwrap().to_string(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn skips_unsolved_parts() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    record(Stage::Parse, None, &[100]),
+                    record(Stage::Part(Part::One), Some("0"), &[100]),
+                    record(Stage::Part(Part::Two), None, &[1_000]),
                 ],
                 day!(1),
             );
+            assert_approx_eq!(res.total_nanos, 200_f64);
+            assert_eq!(res.parse.is_some(), true);
+            assert_eq!(res.part_1.is_some(), true);
+            assert_eq!(res.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn collects_missing_parts() {
+            let res = timing_from_records(&[], day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
@@ -1,35 +1,221 @@
-/// Encapsulates code that interacts with solution functions.
+//! Encapsulates code that interacts with solution functions.
+
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{stdin, stdout, Write};
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, AocClientError, SubmissionOutcome};
+use crate::template::input::InputSource;
+use crate::template::output::{OutputFormat, PartRecord, Stage, Status};
+use crate::template::stats::BenchStats;
+use crate::template::submissions::{Submission, SubmissionCheck, SubmissionLog};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Part, PuzzleId, ANSI_ITALIC, ANSI_RESET};
+
+/// Prefix of the line that summarizes the sample distribution of a benched part.
+const STATS_PREFIX: &str = "  ↳ ";
+
+const BENCH_BUDGET_ENV: &str = "AOC_BENCH_BUDGET_MS";
+const BENCH_WARMUP_ENV: &str = "AOC_BENCH_WARMUP_MS";
+const BENCH_MIN_SAMPLES_ENV: &str = "AOC_BENCH_MIN_SAMPLES";
+const BENCH_MAX_SAMPLES_ENV: &str = "AOC_BENCH_MAX_SAMPLES";
+
+/// Controls how a part is benched when running with `--time`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate time spent collecting samples.
+    pub budget: Duration,
+    /// Time spent running the part before samples are collected.
+    /// Skipped for parts whose first run already took longer.
+    pub warmup: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            warmup: Duration::from_millis(100),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads the config from `AOC_BENCH_*` environment variables, using defaults for unset ones.
+    pub fn from_env() -> Result<Self, String> {
+        fn read_var(key: &str) -> Result<Option<u64>, String> {
+            match env::var(key) {
+                Ok(value) => value.parse().map(Some).map_err(|_| {
+                    format!("expected {key} to be a non-negative integer, got `{value}`.")
+                }),
+                Err(_) => Ok(None),
+            }
+        }
+
+        let mut config = Self::default();
+
+        if let Some(ms) = read_var(BENCH_BUDGET_ENV)? {
+            config.budget = Duration::from_millis(ms);
+        }
+        if let Some(ms) = read_var(BENCH_WARMUP_ENV)? {
+            config.warmup = Duration::from_millis(ms);
+        }
+        if let Some(n) = read_var(BENCH_MIN_SAMPLES_ENV)? {
+            config.min_samples = n.into();
+        }
+        if let Some(n) = read_var(BENCH_MAX_SAMPLES_ENV)? {
+            config.max_samples = n.into();
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_samples == 0 {
+            return Err("the minimum number of samples has to be at least 1.".into());
+        }
+        if self.min_samples > self.max_samples {
+            return Err(format!(
+                "the minimum number of samples ({}) exceeds the maximum ({}).",
+                self.min_samples, self.max_samples
+            ));
+        }
+        Ok(())
+    }
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+    /// Environment variables that mirror this config to a child solution process.
+    pub fn to_env(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (BENCH_BUDGET_ENV, self.budget.as_millis().to_string()),
+            (BENCH_WARMUP_ENV, self.warmup.as_millis().to_string()),
+            (BENCH_MIN_SAMPLES_ENV, self.min_samples.to_string()),
+            (BENCH_MAX_SAMPLES_ENV, self.max_samples.to_string()),
+        ]
+    }
+}
+
+/// Read the input selected by the arguments of the solution binary, the puzzle input by default.
+pub fn read_input(puzzle: PuzzleId) -> String {
+    InputSource::from_args(&env::args().collect::<Vec<String>>())
+        .and_then(|source| source.read(puzzle))
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        })
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Run the parse stage of a solution. It is timed like a part and its result is shared by all parts.
+pub fn run_parse<'a, P>(func: impl Fn(&'a str) -> P, input: &'a str, day: Day) -> P {
+    let format = OutputFormat::from_args();
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (result, stats) = run_timed(func, input, format == OutputFormat::Human, |_| {
+        print!("{}:", Stage::Parse);
+    });
 
+    print_record(&PartRecord::new(day, Stage::Parse, None, stats), format);
+
+    result
+}
+
+/// Run a part of a solution. `answers` collects the answers of this run, indexed by part,
+/// so that a submission can be compared with the answer of the other part.
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: Part,
+    answers: &mut [Option<String>; 2],
+) {
+    let format = OutputFormat::from_args();
+    let part_str = Stage::Part(part).to_string();
+
+    let (result, stats) = run_timed(func, input, format == OutputFormat::Human, |result| {
+        print_result(result, &part_str, "");
+    });
+
+    let answer = result.as_ref().map(ToString::to_string);
+    print_record(
+        &PartRecord::new(puzzle.day, Stage::Part(part), answer.clone(), stats),
+        format,
+    );
+
+    answers[part_index(part)] = answer;
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(
+            result,
+            puzzle,
+            part,
+            answers[part_index(part.other())].as_deref(),
+        );
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+fn part_index(part: Part) -> usize {
+    usize::from(part.into_inner() - 1)
+}
+
+/// Like [`run_parse`], but returns the record instead of printing it. Used to run solutions in-process.
+pub fn solve_parse<'a, P>(
+    func: impl Fn(&'a str) -> P,
+    input: &'a str,
+    day: Day,
+) -> (P, PartRecord) {
+    let (result, stats) = run_timed(func, input, false, |_| {});
+    (result, PartRecord::new(day, Stage::Parse, None, stats))
+}
+
+/// Like [`run_part`], but returns the record instead of printing it. Used to run solutions in-process.
+pub fn solve_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: Part,
+) -> PartRecord {
+    let (result, stats) = run_timed(func, input, false, |_| {});
+    let answer = result.as_ref().map(ToString::to_string);
+    PartRecord::new(day, Stage::Part(part), answer, stats)
+}
+
+/// Print the final result of a stage in the given format.
+pub fn print_record(record: &PartRecord, format: OutputFormat) {
+    match format {
+        OutputFormat::Json => println!("{}", record.to_json()),
+        OutputFormat::Human => {
+            let duration_str = format_duration(&record.stats.mean, record.stats.samples);
+
+            if record.stage == Stage::Parse {
+                print!("\r");
+                println!("{}:{duration_str}", record.stage);
+            } else {
+                print_result(&record.answer, &record.stage.to_string(), &duration_str);
+            }
+
+            if record.status == Status::Solved && record.stats.samples > 1 {
+                println!("{STATS_PREFIX}{}", format_stats(&record.stats));
+            }
+        }
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether the `--time` flag is passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is warmed up and then benched according to [`BenchConfig`]
+///     (by default approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The `hook` and other intermediate output are only printed if `show_progress` is set.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    show_progress: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, BenchStats) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -41,27 +227,54 @@ fn run_timed<I: Clone, T>(
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    if show_progress {
+        hook(&result);
+    }
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let samples = if std::env::args().any(|x| x == "--time") {
+        let config = BenchConfig::from_env().unwrap_or_else(|e| {
+            eprintln!("Invalid benchmark config: {e}");
+            process::exit(1);
+        });
+        bench(func, input, &base_time, &config, show_progress)
     } else {
-        (base_time, 1)
+        vec![base_time]
     };
 
-    (result, run.0, run.1)
+    // NOTE: both branches produce at least one sample.
+    (result, BenchStats::from_samples(&samples).unwrap())
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+    show_progress: bool,
+) -> Vec<Duration> {
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    // run the function without recording samples so caches and branch predictors are warm.
+    // the warm-up runs double as a better estimate of the per-iteration time than the first run.
+    // parts slower than the warm-up budget were already warmed by their first run.
+    let mut estimate = *base_time;
+    if !config.warmup.is_zero() && *base_time < config.warmup {
+        let mut warmup_iterations = 0;
+        let warmup_timer = Instant::now();
+        while warmup_timer.elapsed() < config.warmup {
+            black_box(func(black_box(input.clone())));
+            warmup_iterations += 1;
+        }
+        estimate = warmup_timer.elapsed() / warmup_iterations;
+    }
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench_iterations = (config.budget.as_nanos() / cmp::max(estimate.as_nanos(), 10))
+        .clamp(config.min_samples, config.max_samples);
 
-    let mut timers: Vec<Duration> = vec![];
+    let mut timers: Vec<Duration> = Vec::with_capacity(bench_iterations as usize);
 
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
@@ -71,19 +284,7 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
-
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+    timers
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -94,6 +295,13 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
+fn format_stats(stats: &BenchStats) -> String {
+    format!(
+        "min {:.1?}, median {:.1?}, p95 {:.1?}, max {:.1?}, σ {:.1?}, {} outliers",
+        stats.min, stats.median, stats.p95, stats.max, stats.std_dev, stats.outliers
+    )
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -131,39 +339,157 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///
+/// Answers that cannot be right according to earlier submissions are refused, suspicious answers need
+/// to be confirmed. `--force` skips both. An answer is suspicious if it equals the recorded answer of the
+/// other part, or `other_part_answer`, the answer of the other part if it already ran in this run.
+///
+/// Every submission is appended to the submission log. Correct answers are also recorded in the
+/// answers file, so `cargo verify` can check them later.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+    puzzle: PuzzleId,
+    part: Part,
+    other_part_answer: Option<&str>,
+) -> Option<Result<SubmissionOutcome, AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
-
     let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let part_submit = match args.get(part_index).map(|x| x.parse::<Part>()) {
+        Some(Ok(part)) => part,
+        Some(Err(e)) => {
+            eprintln!("Cannot submit: {e}.");
+            process::exit(1);
+        }
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+            process::exit(1);
+        }
     };
 
     if part_submit != part {
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    // NOTE: answers for other inputs are never right, so they are not worth a submission.
+    if InputSource::from_args(&args) != Ok(InputSource::Puzzle) {
+        eprintln!("Cannot submit: only answers for the puzzle input can be submitted.");
+        process::exit(1);
+    }
+
+    let client = AocClient::from_env(puzzle.year).unwrap_or_else(|e| {
+        eprintln!("Cannot submit: {e}");
+        process::exit(1);
+    });
+
+    let PuzzleId { year, day } = puzzle;
+    let result = result.to_string();
+    let log = SubmissionLog::read_from_file(year);
+    let previous = log.for_part(day, part);
+    if !previous.is_empty() {
+        let summary: Vec<String> = previous
+            .iter()
+            .map(|s| format!("{} ({})", s.answer, s.outcome))
+            .collect();
+        println!("Previous submissions: {}", summary.join(", "));
+    }
+
+    let stored = Answers::read_from_file(year);
+    if let Err(e) = &stored {
+        eprintln!("⚠ Warning: {e}");
+    }
+    let stored_answer = stored.as_ref().ok().and_then(|a| a.get(day, part.other()));
+    let other_part_answers: Vec<&str> = [stored_answer, other_part_answer]
+        .into_iter()
+        .flatten()
+        .collect();
+    let checks = log.check(day, part, &result, &other_part_answers);
+
+    if !check_submission(&checks, args.contains(&"--force".into())) {
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let output = client.submit(day, part, &result);
+
+    let outcome = match &output {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            return Some(output);
+        }
+    };
+
+    print_outcome(outcome, &result, part);
+
+    if let Err(e) =
+        SubmissionLog::append_file(year, &Submission::new(day, part, &result, outcome.clone()))
+    {
+        eprintln!("Could not append to submission log: {e}");
+    }
+
+    // NOTE: never write over an answers file that could not be parsed, it would lose all other answers.
+    if *outcome == SubmissionOutcome::Correct {
+        match Answers::read_from_file(year) {
+            Ok(mut answers) => {
+                answers.set(day, part, &result);
+                match answers.store_file(year) {
+                    Ok(()) => println!("Recorded answer for day {day}, part {part}."),
+                    Err(e) => eprintln!("Could not record answer: {e}"),
+                }
+            }
+            Err(e) => eprintln!("Not recording answer: {e}"),
+        }
+    }
+
+    Some(output)
+}
+
+/// Prints the checks of an answer and decides whether to submit it.
+fn check_submission(checks: &[SubmissionCheck], force: bool) -> bool {
+    for check in checks {
+        if check.is_refusal() {
+            eprintln!("✖ Refusing to submit: {check}");
+        } else {
+            eprintln!("⚠ Warning: {check}");
+        }
+    }
+
+    if force || checks.is_empty() {
+        return true;
+    }
+
+    if checks.iter().any(SubmissionCheck::is_refusal) {
+        eprintln!("Append `--force` to submit anyway.");
+        return false;
+    }
+
+    print!("Submit anyway? [y/N] ");
+    let _ = stdout().flush();
+    let mut reply = String::new();
+    // NOTE: a closed stdin reads as an empty reply, i.e. no.
+    let _ = stdin().read_line(&mut reply);
+    matches!(reply.trim(), "y" | "Y" | "yes")
+}
+
+fn print_outcome(outcome: &SubmissionOutcome, answer: &str, part: Part) {
+    match outcome {
+        SubmissionOutcome::Correct => println!("✔ {answer} is the right answer."),
+        SubmissionOutcome::TooHigh => {
+            println!("✖ {answer} is not the right answer, it is too high.")
+        }
+        SubmissionOutcome::TooLow => println!("✖ {answer} is not the right answer, it is too low."),
+        SubmissionOutcome::Wrong => println!("✖ {answer} is not the right answer."),
+        SubmissionOutcome::RateLimited { .. } => {
+            println!("⏳ The answer was not checked, submitted too recently ({outcome}).");
+        }
+        SubmissionOutcome::AlreadySolved => println!("Part {part} is already solved."),
+        SubmissionOutcome::Unknown(message) => println!("{message}"),
+    }
 }
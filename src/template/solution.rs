@@ -0,0 +1,97 @@
+//! Typed interface of a day, as an alternative to free `part_one` / `part_two` functions.
+//! Pass an implementor to the `solution!` macro, e.g. `solution!(7, BridgeRepair)`.
+
+use std::fmt::Display;
+
+/// The solution of a single day.
+///
+/// The input is parsed once and passed by reference to both parts. Parsing is timed as its own stage.
+///
+/// ```
+/// # use advent_of_code::template::Solution;
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     type Input = Vec<u32>;
+///     type Answer1 = u32;
+///     type Answer2 = u32;
+///
+///     fn parse(input: &str) -> Self::Input {
+///         input.lines().filter_map(|line| line.parse().ok()).collect()
+///     }
+///
+///     fn part_one(input: &Self::Input) -> Option<u32> {
+///         Some(input.iter().sum())
+///     }
+/// }
+///
+/// assert_eq!(Sum::solve("1\n2\n3"), (Some(6), None));
+/// ```
+pub trait Solution {
+    /// The parsed input that is shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part_one(input: &Self::Input) -> Option<Self::Answer1>;
+
+    /// Solves the second part. Days without a second part, like the last day, can leave this out.
+    fn part_two(_input: &Self::Input) -> Option<Self::Answer2> {
+        None
+    }
+
+    /// Parses the input and solves both parts.
+    fn solve(input: &str) -> (Option<Self::Answer1>, Option<Self::Answer2>) {
+        let input = Self::parse(input);
+        (Self::part_one(&input), Self::part_two(&input))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Solution;
+    use crate::{
+        day,
+        template::{output::Stage, runner::solve_part, Part},
+    };
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Input = Vec<usize>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Self::Input {
+            input.lines().map(str::len).collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Option<usize> {
+            input.iter().max().copied()
+        }
+
+        fn part_two(input: &Self::Input) -> Option<String> {
+            Some(format!("{input:?}"))
+        }
+    }
+
+    #[test]
+    fn solves_both_parts() {
+        assert_eq!(
+            Lengths::solve("a\nabc\nab"),
+            (Some(3), Some("[1, 3, 2]".into()))
+        );
+    }
+
+    #[test]
+    fn runs_parts_of_a_solution() {
+        let input = Lengths::parse("abcd");
+        let record = solve_part(Lengths::part_one, &input, day!(1), Part::One);
+        assert_eq!(record.stage, Stage::Part(Part::One));
+        assert_eq!(record.answer.as_deref(), Some("4"));
+    }
+}
@@ -0,0 +1,139 @@
+//! Summary statistics for a set of benchmark samples.
+
+use std::time::Duration;
+
+/// Describes the distribution of a set of benchmark samples.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR beyond the quartiles).
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics over `samples`. Returns [`None`] for an empty slice.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
+
+        let len = nanos.len();
+        let mean = nanos.iter().sum::<u128>() / len as u128;
+
+        let median = if len.is_multiple_of(2) {
+            (nanos[len / 2 - 1] + nanos[len / 2]) / 2
+        } else {
+            nanos[len / 2]
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = nanos
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let iqr = q3 - q1;
+        let lower_fence = q1.saturating_sub(iqr * 3 / 2);
+        let upper_fence = q3 + iqr * 3 / 2;
+
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x < lower_fence || x > upper_fence)
+            .count();
+
+        Some(Self {
+            samples: len as u128,
+            mean: from_nanos(mean),
+            min: from_nanos(nanos[0]),
+            max: from_nanos(nanos[len - 1]),
+            median: from_nanos(median),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            p95: from_nanos(percentile(&nanos, 95.0)),
+            outliers: outliers as u128,
+        })
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn from_nanos(nanos: u128) -> Duration {
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(nanos as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::BenchStats;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.max, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_summary() {
+        let stats = BenchStats::from_samples(&nanos(&[40, 10, 30, 20])).unwrap();
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(40));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        // population standard deviation of 10, 20, 30, 40 is ~11.18.
+        assert_eq!(stats.std_dev, Duration::from_nanos(11));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let mut samples = vec![100; 19];
+        samples.push(10_000);
+        let stats = BenchStats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.max, Duration::from_nanos(10_000));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.outliers, 1);
+    }
+}
@@ -0,0 +1,410 @@
+//! Append-only log of submitted answers and how the server judged them.
+//! Every `cargo solve <day> --submit <part>` appends one line of JSON to `data/<year>/submissions.jsonl`.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::SubmissionOutcome;
+use crate::template::{Day, Part, Year};
+
+/// Name of the submission log in the data directory of a year.
+static SUBMISSIONS_FILE_NAME: &str = "submissions.jsonl";
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub day: Day,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+}
+
+impl Submission {
+    pub fn new(day: Day, part: Part, answer: &str, outcome: SubmissionOutcome) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            day,
+            part,
+            answer: answer.into(),
+            outcome,
+        }
+    }
+}
+
+/// Reason to hold back an answer before submitting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmissionCheck {
+    /// The same answer was submitted before and rejected.
+    AlreadyRejected(SubmissionOutcome),
+    /// The answer is at or above an answer that was too high.
+    AboveUpperBound(String),
+    /// The answer is at or below an answer that was too low.
+    BelowLowerBound(String),
+    /// The answer is `0`, which usually means the solution is not finished.
+    Zero,
+    /// The answer is identical to the accepted answer of the other part.
+    SameAsOtherPart,
+}
+
+impl SubmissionCheck {
+    /// Refusals cannot be right. Everything else is only suspicious.
+    pub fn is_refusal(&self) -> bool {
+        matches!(
+            self,
+            SubmissionCheck::AlreadyRejected(_)
+                | SubmissionCheck::AboveUpperBound(_)
+                | SubmissionCheck::BelowLowerBound(_)
+        )
+    }
+}
+
+impl Display for SubmissionCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionCheck::AlreadyRejected(outcome) => {
+                write!(f, "this answer was already rejected ({outcome}).")
+            }
+            SubmissionCheck::AboveUpperBound(bound) => {
+                write!(
+                    f,
+                    "{bound} was too high, so this answer is too high as well."
+                )
+            }
+            SubmissionCheck::BelowLowerBound(bound) => {
+                write!(f, "{bound} was too low, so this answer is too low as well.")
+            }
+            SubmissionCheck::Zero => write!(f, "the answer is 0."),
+            SubmissionCheck::SameAsOtherPart => {
+                write!(
+                    f,
+                    "the answer is identical to the answer of the other part."
+                )
+            }
+        }
+    }
+}
+
+/// All submissions, in the order they were made.
+#[derive(Clone, Debug, Default)]
+pub struct SubmissionLog {
+    pub entries: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Appends a submission to the log file of a year, creating it if necessary.
+    pub fn append_file(year: Year, submission: &Submission) -> Result<(), Error> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(year.data_path(SUBMISSIONS_FILE_NAME))?;
+
+        // NOTE: serializing a JSON object built from strings and numbers does not fail.
+        writeln!(file, "{}", JsonValue::from(submission).stringify().unwrap())
+    }
+
+    /// Rehydrate the log of a year from its file. If not present, returns an empty log.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(year.data_path(SUBMISSIONS_FILE_NAME))
+            .map(|content| SubmissionLog::from_lines(&content))
+            .unwrap_or_default()
+    }
+
+    /// Parses one submission per line. Malformed lines are reported and skipped.
+    pub fn from_lines(content: &str) -> Self {
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(i, line)| match Submission::try_from(line) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    eprintln!("Skipping line {} of submission log: {e}", i + 1);
+                    None
+                }
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    pub fn for_part(&self, day: Day, part: Part) -> Vec<&Submission> {
+        self.entries
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect()
+    }
+
+    /// Checks an answer against earlier submissions of the same part and against the known
+    /// answers of the other part, e.g. the accepted one and the one of the current run.
+    /// Bounds only apply to numeric answers.
+    pub fn check(
+        &self,
+        day: Day,
+        part: Part,
+        answer: &str,
+        other_part_answers: &[&str],
+    ) -> Vec<SubmissionCheck> {
+        let previous = self.for_part(day, part);
+        let mut checks = vec![];
+
+        if let Some(rejected) = previous
+            .iter()
+            .find(|s| s.answer == answer && s.outcome.is_rejected())
+        {
+            checks.push(SubmissionCheck::AlreadyRejected(rejected.outcome.clone()));
+        } else if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome: SubmissionOutcome| {
+                previous
+                    .iter()
+                    .filter(move |s| s.outcome == outcome)
+                    .filter_map(|s| s.answer.parse::<i128>().ok())
+            };
+
+            if let Some(upper) = bound(SubmissionOutcome::TooHigh).min() {
+                if value >= upper {
+                    checks.push(SubmissionCheck::AboveUpperBound(upper.to_string()));
+                }
+            }
+
+            if let Some(lower) = bound(SubmissionOutcome::TooLow).max() {
+                if value <= lower {
+                    checks.push(SubmissionCheck::BelowLowerBound(lower.to_string()));
+                }
+            }
+        }
+
+        if answer.trim() == "0" {
+            checks.push(SubmissionCheck::Zero);
+        }
+
+        if other_part_answers.contains(&answer) {
+            checks.push(SubmissionCheck::SameAsOtherPart);
+        }
+
+        checks
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let (outcome, wait, message) = match &value.outcome {
+            SubmissionOutcome::Correct => ("correct", None, None),
+            SubmissionOutcome::TooHigh => ("too_high", None, None),
+            SubmissionOutcome::TooLow => ("too_low", None, None),
+            SubmissionOutcome::Wrong => ("wrong", None, None),
+            SubmissionOutcome::RateLimited { wait } => ("rate_limited", *wait, None),
+            SubmissionOutcome::AlreadySolved => ("already_solved", None, None),
+            SubmissionOutcome::Unknown(message) => ("unknown", None, Some(message.clone())),
+        };
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part".into(),
+            JsonValue::Number(f64::from(value.part.into_inner())),
+        );
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert("outcome".into(), JsonValue::String(outcome.into()));
+        map.insert(
+            "wait_secs".into(),
+            wait.map_or(JsonValue::Null, |w| JsonValue::Number(w.as_secs() as f64)),
+        );
+        map.insert(
+            "message".into(),
+            message.map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for Submission {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|&n| n as u64)
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let day =
+            Day::from_str(string("day")?).or(Err("Expected submission.day to be a Day struct."))?;
+
+        let part = u8::try_from(number("part")?)
+            .ok()
+            .and_then(Part::new)
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let outcome = match string("outcome")?.as_str() {
+            "correct" => SubmissionOutcome::Correct,
+            "too_high" => SubmissionOutcome::TooHigh,
+            "too_low" => SubmissionOutcome::TooLow,
+            "wrong" => SubmissionOutcome::Wrong,
+            "rate_limited" => SubmissionOutcome::RateLimited {
+                wait: number("wait_secs").ok().map(Duration::from_secs),
+            },
+            "already_solved" => SubmissionOutcome::AlreadySolved,
+            "unknown" => SubmissionOutcome::Unknown(string("message").cloned().unwrap_or_default()),
+            _ => return Err("Expected submission.outcome to be a known outcome.".into()),
+        };
+
+        Ok(Submission {
+            timestamp: number("timestamp")?,
+            day,
+            part,
+            answer: string("answer")?.clone(),
+            outcome,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Submission, SubmissionCheck, SubmissionLog};
+    use crate::{
+        day,
+        template::{aoc_client::SubmissionOutcome, Day, Part},
+    };
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn submission(day: Day, part: Part, answer: &str, outcome: SubmissionOutcome) -> Submission {
+        Submission {
+            timestamp: 1_733_000_000,
+            day,
+            part,
+            answer: answer.into(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let outcomes = [
+            SubmissionOutcome::Correct,
+            SubmissionOutcome::TooHigh,
+            SubmissionOutcome::TooLow,
+            SubmissionOutcome::Wrong,
+            SubmissionOutcome::RateLimited {
+                wait: Some(Duration::from_secs(72)),
+            },
+            SubmissionOutcome::RateLimited { wait: None },
+            SubmissionOutcome::AlreadySolved,
+            SubmissionOutcome::Unknown("Huh?".into()),
+        ];
+
+        for outcome in outcomes {
+            let entry = submission(day!(1), Part::Two, "1234", outcome);
+            let line = JsonValue::from(&entry).stringify().unwrap();
+            assert_eq!(Submission::try_from(line.as_str()).unwrap(), entry);
+        }
+    }
+
+    #[test]
+    fn filters_by_part() {
+        let lines = [
+            submission(day!(1), Part::One, "10", SubmissionOutcome::TooLow),
+            submission(day!(1), Part::One, "20", SubmissionOutcome::Correct),
+            submission(day!(1), Part::Two, "30", SubmissionOutcome::Wrong),
+            submission(day!(2), Part::One, "40", SubmissionOutcome::TooHigh),
+        ]
+        .iter()
+        .map(|s| JsonValue::from(s).stringify().unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+        let log = SubmissionLog::from_lines(&format!("{lines}\n{{\"day\":\"01\",\"part\":3}}\n"));
+        assert_eq!(log.entries.len(), 4);
+
+        let answers: Vec<&str> = log
+            .for_part(day!(1), Part::One)
+            .iter()
+            .map(|s| s.answer.as_str())
+            .collect();
+        assert_eq!(answers, ["10", "20"]);
+    }
+
+    #[test]
+    fn checks_answers() {
+        let log = SubmissionLog {
+            entries: vec![
+                submission(day!(1), Part::One, "100", SubmissionOutcome::TooLow),
+                submission(day!(1), Part::One, "150", SubmissionOutcome::TooLow),
+                submission(day!(1), Part::One, "500", SubmissionOutcome::TooHigh),
+                submission(day!(1), Part::One, "300", SubmissionOutcome::Wrong),
+                submission(day!(1), Part::One, "abc", SubmissionOutcome::Wrong),
+                submission(day!(1), Part::Two, "900", SubmissionOutcome::TooHigh),
+            ],
+        };
+
+        assert!(log.check(day!(1), Part::One, "200", &[]).is_empty());
+        assert!(log.check(day!(2), Part::One, "100", &[]).is_empty());
+        assert!(log.check(day!(1), Part::One, "xyz", &[]).is_empty());
+        assert_eq!(
+            log.check(day!(1), Part::One, "300", &[]),
+            [SubmissionCheck::AlreadyRejected(SubmissionOutcome::Wrong)]
+        );
+        assert_eq!(
+            log.check(day!(1), Part::One, "abc", &[]),
+            [SubmissionCheck::AlreadyRejected(SubmissionOutcome::Wrong)]
+        );
+        assert_eq!(
+            log.check(day!(1), Part::One, "150", &[]),
+            [SubmissionCheck::AlreadyRejected(SubmissionOutcome::TooLow)]
+        );
+        assert_eq!(
+            log.check(day!(1), Part::One, "120", &[]),
+            [SubmissionCheck::BelowLowerBound("150".into())]
+        );
+        assert_eq!(
+            log.check(day!(1), Part::One, "501", &[]),
+            [SubmissionCheck::AboveUpperBound("500".into())]
+        );
+        assert_eq!(
+            log.check(day!(1), Part::Two, "0", &[]),
+            [SubmissionCheck::Zero]
+        );
+        assert_eq!(
+            log.check(day!(1), Part::Two, "42", &["42"]),
+            [SubmissionCheck::SameAsOtherPart]
+        );
+        assert!(log.check(day!(1), Part::One, "120", &[])[0].is_refusal());
+        assert!(!SubmissionCheck::Zero.is_refusal());
+    }
+}
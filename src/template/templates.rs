@@ -0,0 +1,211 @@
+//! Module templates used by `cargo scaffold`.
+//! Templates are read from `templates/<name>.rs.tmpl`. If present, `templates/day.rs.tmpl` replaces the built-in template.
+
+use std::{fmt::Display, fs, io, path::Path};
+
+use crate::template::examples::{answer_type, render_tests, ExampleFile, Expected};
+use crate::template::{Day, Part, PuzzleId, Year};
+
+pub const TEMPLATES_DIR: &str = "templates";
+const TEMPLATE_EXTENSION: &str = ".rs.tmpl";
+const DEFAULT_TEMPLATE_NAME: &str = "day";
+
+const BUILTIN_TEMPLATE: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+#[derive(Debug)]
+pub enum TemplateError {
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    IO(io::Error),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::NotFound { name, available } if available.is_empty() => write!(
+                f,
+                "template \"{name}\" not found. Add it as {TEMPLATES_DIR}/{name}{TEMPLATE_EXTENSION}."
+            ),
+            TemplateError::NotFound { name, available } => write!(
+                f,
+                "template \"{name}\" not found. Available templates: {}.",
+                available.join(", ")
+            ),
+            TemplateError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for TemplateError {
+    fn from(e: io::Error) -> Self {
+        TemplateError::IO(e)
+    }
+}
+
+/// Values substituted for the placeholders of a template.
+pub struct TemplateContext {
+    pub day: Day,
+    pub year: Year,
+    pub title: Option<String>,
+    pub example_files: Vec<ExampleFile>,
+    pub expected: Vec<Expected>,
+}
+
+/// Loads the template with the given name, or the default template if `name` is [`None`].
+pub fn load(name: Option<&str>) -> Result<String, TemplateError> {
+    let Some(name) = name else {
+        return match fs::read_to_string(template_path(DEFAULT_TEMPLATE_NAME)) {
+            Ok(template) => Ok(template),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BUILTIN_TEMPLATE.into()),
+            Err(e) => Err(e.into()),
+        };
+    };
+
+    match fs::read_to_string(template_path(name)) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(TemplateError::NotFound {
+            name: name.into(),
+            available: available(),
+        }),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Names of the templates in the templates directory.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .strip_suffix(TEMPLATE_EXTENSION)
+                        .map(String::from)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    names.sort();
+    names
+}
+
+/// Replaces all placeholders of a template:
+///
+/// - `%DAY_NUMBER%`: day without padding, e.g. `1`.
+/// - `%DAY%`: day with padding, e.g. `01`.
+/// - `%YEAR%`: year of the puzzle, e.g. `2024`.
+/// - `%PUZZLE_TITLE%`: title of the downloaded puzzle. Lines with this placeholder are left out if it is unknown.
+/// - `%EXAMPLE_FILES%`: names of the example files, e.g. `12-1.txt, 12-2.txt`.
+/// - `%PART_ONE_TYPE%`, `%PART_TWO_TYPE%`: answer types that fit the expected answers, `usize` by default.
+/// - `%EXAMPLE_TESTS%`: tests for the example files.
+pub fn render(template: &str, context: &TemplateContext) -> String {
+    let id = PuzzleId::new(context.year, context.day);
+    let example_files: Vec<String> = context
+        .example_files
+        .iter()
+        .filter_map(|file| {
+            Path::new(&file.path(id))
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+        .collect();
+
+    // NOTE: leave out lines like `// Day 01: %PUZZLE_TITLE%` instead of keeping a dangling header.
+    let template: String = if context.title.is_some() {
+        template.to_string()
+    } else {
+        template
+            .split_inclusive('\n')
+            .filter(|line| !line.contains("%PUZZLE_TITLE%"))
+            .collect()
+    };
+
+    template
+        .replace("%DAY_NUMBER%", &context.day.into_inner().to_string())
+        .replace("%DAY%", &context.day.to_string())
+        .replace("%YEAR%", &context.year.to_string())
+        .replace(
+            "%PUZZLE_TITLE%",
+            context.title.as_deref().unwrap_or_default(),
+        )
+        .replace("%EXAMPLE_FILES%", &example_files.join(", "))
+        .replace("%PART_ONE_TYPE%", answer_type(&context.expected, Part::One))
+        .replace("%PART_TWO_TYPE%", answer_type(&context.expected, Part::Two))
+        .replace(
+            "%EXAMPLE_TESTS%",
+            &render_tests(context.day, &context.example_files, &context.expected),
+        )
+}
+
+fn template_path(name: &str) -> String {
+    format!("{TEMPLATES_DIR}/{name}{TEMPLATE_EXTENSION}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, TemplateContext, BUILTIN_TEMPLATE};
+    use crate::{
+        day,
+        template::{
+            examples::{ExampleFile, Expected},
+            Part,
+        },
+        year,
+    };
+
+    fn get_mock_context() -> TemplateContext {
+        TemplateContext {
+            day: day!(7),
+            year: year!(2024),
+            title: Some("Bridge Repair".into()),
+            example_files: vec![
+                ExampleFile { suffix: Some(1) },
+                ExampleFile { suffix: Some(2) },
+            ],
+            expected: vec![Expected {
+                file: ExampleFile { suffix: Some(2) },
+                part: Part::Two,
+                answer: "abc".into(),
+            }],
+        }
+    }
+
+    #[test]
+    fn replaces_placeholders() {
+        let template = "// %YEAR% day %DAY% (%DAY_NUMBER%): %PUZZLE_TITLE%, examples: %EXAMPLE_FILES%\nfn a() -> Option<%PART_ONE_TYPE%> {}\nfn b() -> Option<%PART_TWO_TYPE%> {}";
+
+        assert_eq!(
+            render(template, &get_mock_context()),
+            "// 2024 day 07 (7): Bridge Repair, examples: 07-1.txt, 07-2.txt\nfn a() -> Option<usize> {}\nfn b() -> Option<String> {}"
+        );
+    }
+
+    #[test]
+    fn leaves_out_unknown_titles() {
+        let context = TemplateContext {
+            title: None,
+            ..get_mock_context()
+        };
+        assert_eq!(
+            render("// Day %DAY%: %PUZZLE_TITLE%\n// %YEAR%\n", &context),
+            "// 2024\n"
+        );
+    }
+
+    #[test]
+    fn renders_builtin_template() {
+        let module = render(BUILTIN_TEMPLATE, &get_mock_context());
+        assert!(module.starts_with("advent_of_code::solution!(7);"));
+        assert!(module.contains("pub fn part_two(input: &str) -> Option<String> {"));
+        assert!(module.contains("fn test_day_7_part_two_from_example_2() {"));
+        assert!(!module.contains('%'));
+    }
+}
@@ -1,19 +1,77 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::Duration,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::output::Stage;
+use crate::template::stats::BenchStats;
+use crate::template::{Day, Part, Year};
+
+/// Name of the timings file in the data directory of a year.
+static TIMINGS_FILE_NAME: &str = "timings.json";
+
+/// Version of the JSON schema written by [`Timings::store_file`].
+/// Files without a version use the schema 1 layout, with durations stored as display strings.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Benchmark result of a single stage of a day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StageTiming {
+    /// Mean duration in nanoseconds.
+    pub nanos: f64,
+    /// Number of samples the mean was computed from. `0` if unknown, e.g. for migrated timings.
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+}
+
+impl StageTiming {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_stats(stats: BenchStats) -> Self {
+        Self {
+            nanos: stats.mean.as_nanos() as f64,
+            samples: stats.samples,
+            stats: Some(stats),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Duration::from_nanos(self.nanos.round() as u64)
+    }
+}
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+impl Display for StageTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1?}", self.duration())
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub parse: Option<StageTiming>,
+    pub part_1: Option<StageTiming>,
+    pub part_2: Option<StageTiming>,
+    /// Duration of the parse stage and both parts combined.
     pub total_nanos: f64,
 }
 
+impl Timing {
+    pub fn stage(&self, stage: Stage) -> Option<&StageTiming> {
+        match stage {
+            Stage::Parse => self.parse.as_ref(),
+            Stage::Part(Part::One) => self.part_1.as_ref(),
+            Stage::Part(Part::Two) => self.part_2.as_ref(),
+        }
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,19 +80,38 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Path of the timings file backing a readme table. Named tables use e.g. `data/2024/timings.laptop.json`.
+    pub fn file_path(year: Year, table: Option<&str>) -> String {
+        table.map_or_else(
+            || year.data_path(TIMINGS_FILE_NAME),
+            |name| year.data_path(&format!("timings.{name}.json")),
+        )
+    }
+
+    /// Dehydrate timings to the JSON file of a readme table.
+    pub fn store_file(&self, year: Year, table: Option<&str>) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(Timings::file_path(year, table))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// Rehydrate timings from the JSON file of a readme table. If not present, returns empty timings.
+    /// A file that cannot be parsed, e.g. because it was written by a newer version, is an error.
+    pub fn read_from_file(year: Year, table: Option<&str>) -> Result<Self, String> {
+        let path = Timings::file_path(year, table);
+        match fs::metadata(&path) {
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            _ => Timings::read_from_path(&path),
+        }
+    }
+
+    /// Rehydrate timings from a JSON file at `path`, e.g. a named baseline.
+    pub fn read_from_path(path: &str) -> Result<Self, String> {
+        fs::read_to_string(path)
+            .map_err(|x| format!("could not read {path}: {x}"))
+            .and_then(|content| {
+                Timings::try_from(content).map_err(|x| format!("could not parse {path}: {x}"))
+            })
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -51,7 +128,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,6 +137,18 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// Timings of the days that have a puzzle in `year`, dropping stale timings of other days.
+    pub fn in_year(&self, year: Year) -> Self {
+        Timings {
+            data: self
+                .data
+                .iter()
+                .filter(|timing| year.has_day(timing.day))
+                .cloned()
+                .collect(),
+        }
+    }
+
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
@@ -73,6 +162,10 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(SCHEMA_VERSION)),
+        );
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -88,20 +181,42 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let version = match json.get("version") {
+            None => 1,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Some(JsonValue::Number(n)) => *n as u32,
+            Some(_) => return Err("expected `json.version` to be a number.".into()),
+        };
+
+        if version > SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported timings schema version {version}, expected at most {SCHEMA_VERSION}."
+            ));
+        }
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
-        Ok(Timings {
-            data: json_data
+        let data = if version == 1 {
+            json_data
+                .iter()
+                .map(migrate_v1_timing)
+                .collect::<Result<_, _>>()?
+        } else {
+            json_data
                 .iter()
                 .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
-        })
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(Timings { data })
     }
 }
 
@@ -110,67 +225,218 @@ impl TryFrom<String> for Timings {
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
+        let stage = |s: &Option<StageTiming>| s.as_ref().map_or(JsonValue::Null, JsonValue::from);
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+        map.insert("parse".into(), stage(&value.parse));
+        map.insert("part_1".into(), stage(&value.part_1));
+        map.insert("part_2".into(), stage(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+impl TryFrom<&JsonValue> for Timing {
+    type Error = String;
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let stage = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => StageTiming::try_from(v)
+                .map(Some)
+                .map_err(|e| format!("Expected timing.{key} to be null or a stage timing: {e}")),
+            _ => Ok(None),
+        };
+
+        Ok(Timing {
+            day: day_from_json(json)?,
+            parse: stage("parse")?,
+            part_1: stage("part_1")?,
+            part_2: stage("part_2")?,
+            total_nanos: total_nanos_from_json(json)?,
+        })
+    }
+}
+
+/// Reads a timing written with schema 1, where stage durations were stored as display strings
+/// (e.g. `"74.1ms"`) next to optional `<stage>_stats` objects.
+fn migrate_v1_timing(value: &JsonValue) -> Result<Timing, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing to be a JSON object.")?;
+
+    let stage = |key: &str| -> Result<Option<StageTiming>, String> {
+        // NOTE: stats were added later and may be missing in older files.
+        let stats = match json.get(&format!("{key}_stats")) {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
 
+        match json.get(key) {
+            Some(JsonValue::String(s)) => Ok(Some(match stats {
+                Some(stats) => StageTiming::from_stats(stats),
+                None => StageTiming {
+                    nanos: parse_duration_nanos(s)
+                        .ok_or(format!("Expected timing.{key} to be a duration."))?,
+                    samples: 0,
+                    stats: None,
+                },
+            })),
+            Some(JsonValue::Null) => Ok(None),
+            // NOTE: the parse stage was added later and may be missing in older files.
+            None if key == "parse" => Ok(None),
+            _ => Err(format!("Expected timing.{key} to be null or string.")),
+        }
+    };
+
+    Ok(Timing {
+        day: day_from_json(json)?,
+        parse: stage("parse")?,
+        part_1: stage("part_1")?,
+        part_2: stage("part_2")?,
+        total_nanos: total_nanos_from_json(json)?,
+    })
+}
+
+fn day_from_json(json: &HashMap<String, JsonValue>) -> Result<Day, String> {
+    json.get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or("Expected timing.day to be a Day struct.".into())
+}
+
+fn total_nanos_from_json(json: &HashMap<String, JsonValue>) -> Result<f64, String> {
+    json.get("total_nanos")
+        .and_then(|v| v.get::<f64>().copied())
+        .ok_or("Expected timing.total_nanos to be a number.".into())
+}
+
+/// Parses a duration formatted with `{:?}`, e.g. `74.13ns`, `1.2µs`, `3ms` or `2.0s`.
+fn parse_duration_nanos(value: &str) -> Option<f64> {
+    let units = [
+        ("ns", 1.0),
+        ("µs", 1e3),
+        ("us", 1e3),
+        ("ms", 1e6),
+        ("s", 1e9),
+    ];
+
+    units.iter().find_map(|(unit, factor)| {
+        value
+            .strip_suffix(unit)
+            .and_then(|number| number.trim().parse::<f64>().ok())
+            .map(|number| number * factor)
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&StageTiming> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &StageTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         JsonValue::Object(map)
     }
 }
 
-impl TryFrom<&JsonValue> for Timing {
+impl TryFrom<&JsonValue> for StageTiming {
     type Error = String;
 
     fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
         let json = value
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("Expected timing to be a JSON object.")?;
+            .ok_or("Expected stage timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected stage timing.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|&n| n as u128)
+            .ok_or("Expected stage timing.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        Ok(StageTiming {
+            nanos,
+            samples,
+            stats,
+        })
+    }
+}
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+/* -------------------------------------------------------------------------- */
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+#[allow(clippy::cast_precision_loss)]
+fn nanos_to_json(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
 
-        Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("mean_nanos".into(), nanos_to_json(value.mean));
+        map.insert("min_nanos".into(), nanos_to_json(value.min));
+        map.insert("max_nanos".into(), nanos_to_json(value.max));
+        map.insert("median_nanos".into(), nanos_to_json(value.median));
+        map.insert("std_dev_nanos".into(), nanos_to_json(value.std_dev));
+        map.insert("p95_nanos".into(), nanos_to_json(value.p95));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|v| v as u64)
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            samples: number("samples")?.into(),
+            mean: Duration::from_nanos(number("mean_nanos")?),
+            min: Duration::from_nanos(number("min_nanos")?),
+            max: Duration::from_nanos(number("max_nanos")?),
+            median: Duration::from_nanos(number("median_nanos")?),
+            std_dev: Duration::from_nanos(number("std_dev_nanos")?),
+            p95: Duration::from_nanos(number("p95_nanos")?),
+            outliers: number("outliers")?.into(),
         })
     }
 }
@@ -179,48 +445,84 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, template::Day};
 
-    use super::{Timing, Timings};
+    use super::{StageTiming, Timing, Timings};
+
+    fn stage(nanos: f64) -> Option<StageTiming> {
+        Some(StageTiming {
+            nanos,
+            samples: 1,
+            stats: None,
+        })
+    }
+
+    fn timing(day: Day, part_1: Option<f64>, part_2: Option<f64>, total_nanos: f64) -> Timing {
+        Timing {
+            day,
+            parse: None,
+            part_1: part_1.and_then(stage),
+            part_2: part_2.and_then(stage),
+            total_nanos,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
+                timing(day!(1), Some(1e7), Some(2e7), 3e+10),
+                timing(day!(2), Some(3e7), Some(4e7), 7e+10),
+                timing(day!(4), Some(4e7), None, 4e+10),
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{day, template::timings::Timings, year};
+        use std::time::Duration;
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parse": null, "part_1": { "nanos": 1000000, "samples": 10, "stats": null }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, 10);
+            assert_eq!(part_1.to_string(), "1.0ms");
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn migrates_v1_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.13ns", "part_2": "1.2µs", "total_nanos": 1274.13 }, { "day": "02", "part_1": "2.0s", "part_2": null, "total_nanos": 2000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 2);
+            assert_eq!(timings.data[0].parse, None);
+            assert!((timings.data[0].part_1.unwrap().nanos - 74.13).abs() < 1e-6);
+            assert!((timings.data[0].part_2.unwrap().nanos - 1200.0).abs() < 1e-6);
+            assert_eq!(timings.data[0].part_1.unwrap().samples, 0);
+            assert!((timings.data[1].part_1.unwrap().nanos - 2e9).abs() < 1e-6);
+            assert_eq!(timings.data[1].part_2, None);
+        }
+
+        #[test]
+        fn migrates_v1_timing_stats() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "3ms", "part_1": "25.0ns", "part_2": null, "total_nanos": 25, "part_1_stats": { "samples": 4, "mean_nanos": 25, "min_nanos": 10, "max_nanos": 40, "median_nanos": 25, "std_dev_nanos": 11, "p95_nanos": 40, "outliers": 0 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert!((timing.parse.unwrap().nanos - 3e6).abs() < 1e-6);
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.nanos, 25_f64);
+            assert_eq!(part_1.samples, 4);
+            let stats = part_1.stats.unwrap();
+            assert_eq!(stats.min, Duration::from_nanos(10));
+            assert_eq!(stats.max, Duration::from_nanos(40));
+            assert_eq!(stats.std_dev, Duration::from_nanos(11));
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
         #[test]
@@ -243,22 +545,64 @@ mod tests {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_v1_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_future_versions() {
+            let json = r#"{ "version": 99, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn reads_missing_file_as_empty() {
+            let timings = Timings::read_from_file(year!(2015), Some("missing")).unwrap();
+            assert!(timings.data.is_empty());
+        }
     }
 
     mod serialization {
         use super::get_mock_timings;
-        use std::collections::HashMap;
+        use crate::template::{
+            stats::BenchStats,
+            timings::{StageTiming, Timings, SCHEMA_VERSION},
+        };
+        use std::{collections::HashMap, time::Duration};
         use tinyjson::JsonValue;
 
+        #[test]
+        fn roundtrips_timings() {
+            let mut timings = get_mock_timings();
+            let stats =
+                BenchStats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(30)])
+                    .unwrap();
+            timings.data[0].part_1 = Some(StageTiming::from_stats(stats));
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1.unwrap().stats, Some(stats));
+            assert_eq!(parsed.data[0].part_1.unwrap().samples, 2);
+            assert_eq!(parsed.data[0].part_2, timings.data[0].part_2);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
             let value = JsonValue::try_from(timings).unwrap();
+            let json = value.get::<HashMap<String, JsonValue>>().unwrap();
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                json.get("version").unwrap().get::<f64>(),
+                Some(&f64::from(SCHEMA_VERSION))
+            );
+            assert_eq!(
+                json.get("data")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
@@ -269,72 +613,46 @@ mod tests {
     }
 
     mod is_day_complete {
-        use crate::{
-            day,
-            template::timings::{Timing, Timings},
-        };
+        use super::timing;
+        use crate::{day, template::timings::Timings};
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some(1e6), Some(2e6), 3_000_000_f64)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some(1e6), None, 1_000_000_f64)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![timing(day!(1), None, None, 0.0)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
-        use crate::{
-            day,
-            template::timings::{Timing, Timings},
-        };
-
-        use super::get_mock_timings;
+        use super::{get_mock_timings, timing};
+        use crate::{day, template::timings::Timings};
 
         #[test]
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(3), None, None, 0_f64)],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +667,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(2), None, None, 0_f64)],
             };
             let merged = timings.merge(&other);
 
@@ -381,4 +694,22 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+    mod in_year {
+        use super::timing;
+        use crate::{day, template::timings::Timings, year};
+
+        #[test]
+        fn drops_days_outside_calendar() {
+            let timings = Timings {
+                data: vec![
+                    timing(day!(12), None, None, 0_f64),
+                    timing(day!(13), None, None, 0_f64),
+                ],
+            };
+
+            assert_eq!(timings.in_year(year!(2024)).data.len(), 2);
+            assert_eq!(timings.in_year(year!(2025)).data.len(), 1);
+            assert_eq!(timings.in_year(year!(2025)).data[0].day, day!(12));
+        }
+    }
 }
@@ -0,0 +1,314 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{AllDays, Day, LAST_DAY};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+const YEAR_ENV: &str = "AOC_YEAR";
+
+/// Prefix of the variables that override the last day of a year, e.g. `AOC_LAST_DAY_2026`.
+const LAST_DAY_ENV_PREFIX: &str = "AOC_LAST_DAY_";
+
+/// The first year of Advent of Code.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year with a 12-day calendar.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// The last day of a 12-day calendar.
+const SHORT_LAST_DAY: u8 = 12;
+
+/// A valid year of advent (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// The year set by `AOC_YEAR`, usually in `.cargo/config.toml`.
+    pub fn from_env() -> Option<Self> {
+        env::var(YEAR_ENV).ok()?.parse().ok()
+    }
+
+    /// Parses the year of a solution from its file name, e.g. `src/bin/2024-01.rs`.
+    /// Fails to compile when used in a const context with a file that is named differently.
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __from_bin_path(path: &str) -> Self {
+        let bytes = path.as_bytes();
+
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+            start -= 1;
+        }
+
+        if bytes.len() < start + 5 || bytes[start + 4] != b'-' {
+            panic!("expecting solutions to be named like `src/bin/2024-01.rs`");
+        }
+
+        let mut year = 0;
+        let mut i = start;
+        while i < start + 4 {
+            if !bytes[i].is_ascii_digit() {
+                panic!("expecting solutions to be named like `src/bin/2024-01.rs`");
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        if year < FIRST_YEAR {
+            panic!("expecting the year of a solution to be 2015 or later");
+        }
+
+        Self(year)
+    }
+
+    /// The last day with a puzzle in this year: the 25th until 2024, the 12th since 2025.
+    /// Set `AOC_LAST_DAY_<year>` to override this for a calendar of another length.
+    pub fn last_day(self) -> Day {
+        if let Ok(Some(day)) = self.last_day_override() {
+            return day;
+        }
+
+        if self.0 >= FIRST_SHORT_YEAR {
+            Day::__new_unchecked(SHORT_LAST_DAY)
+        } else {
+            Day::__new_unchecked(LAST_DAY)
+        }
+    }
+
+    /// The last day set by `AOC_LAST_DAY_<year>`, e.g. `AOC_LAST_DAY_2026`.
+    /// Fails if the variable is set to something other than a day.
+    pub fn last_day_override(self) -> Result<Option<Day>, String> {
+        let name = format!("{LAST_DAY_ENV_PREFIX}{self}");
+        match env::var(&name) {
+            Ok(value) => value
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|e| format!("{name} is set to \"{value}\", {e}")),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// An iterator that yields every day with a puzzle in this year.
+    pub fn days(self) -> AllDays {
+        AllDays::until(self.last_day())
+    }
+
+    /// Whether this year has a puzzle on the given day.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.last_day()
+    }
+
+    /// Path of a file in the data directory of this year, e.g. `data/2024/answers.json`.
+    pub fn data_path(self, name: &str) -> String {
+        format!("data/{self}/{name}")
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a single puzzle by its year and day.
+///
+/// # Display
+/// This value displays like the name of its solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2024).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2024-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Path of the file of this puzzle in a data folder, e.g. `data/2024/inputs/01.txt`.
+    pub fn data_path(self, folder: &str) -> String {
+        format!("data/{}/{folder}/{}.txt", self.year, self.day)
+    }
+
+    /// Path of the downloaded description of this puzzle, e.g. `data/2024/puzzles/01.md`.
+    pub fn puzzle_path(self) -> String {
+        format!("data/{}/puzzles/{}.md", self.year, self.day)
+    }
+
+    /// Path of the solution of this puzzle, e.g. `src/bin/2024-01.rs`.
+    pub fn bin_path(self) -> String {
+        format!("src/bin/{self}.rs")
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle released today if there is one, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() != 12 {
+            return None;
+        }
+
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        let day = Day::new(u8::try_from(today.day()).ok()?)?;
+        year.has_day(day).then_some(Self::new(year, day))
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of 2015 or later"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Year::new(2024));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+        assert!("abc".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn parses_years_from_bin_paths() {
+        assert_eq!(Year::__from_bin_path("src/bin/2023-01.rs"), year!(2023));
+        assert_eq!(
+            Year::__from_bin_path("/home/user/aoc/src/bin/2024-25.rs"),
+            year!(2024)
+        );
+        assert_eq!(Year::__from_bin_path("src\\bin\\2015-03.rs"), year!(2015));
+    }
+
+    #[test]
+    fn shortens_calendar_from_2025() {
+        assert_eq!(year!(2015).last_day(), day!(25));
+        assert_eq!(year!(2024).last_day(), day!(25));
+        assert_eq!(year!(2025).last_day(), day!(12));
+        assert_eq!(year!(2025).days().count(), 12);
+        assert!(year!(2024).has_day(day!(25)));
+        assert!(year!(2025).has_day(day!(12)));
+        assert!(!year!(2025).has_day(day!(13)));
+    }
+
+    #[test]
+    fn overrides_last_day_from_env() {
+        // NOTE: every test uses its own year, as tests share the environment.
+        std::env::set_var("AOC_LAST_DAY_2016", "10");
+        assert_eq!(year!(2016).last_day(), day!(10));
+        assert!(year!(2016).has_day(day!(10)));
+        assert!(!year!(2016).has_day(day!(11)));
+        assert_eq!(year!(2016).days().count(), 10);
+
+        std::env::set_var("AOC_LAST_DAY_2017", "26");
+        assert!(year!(2017).last_day_override().is_err());
+        assert_eq!(year!(2017).last_day(), day!(25));
+
+        assert_eq!(year!(2018).last_day_override(), Ok(None));
+    }
+
+    #[test]
+    fn builds_paths() {
+        let puzzle = PuzzleId::new(year!(2023), day!(7));
+        assert_eq!(puzzle.to_string(), "2023-07");
+        assert_eq!(puzzle.data_path("inputs"), "data/2023/inputs/07.txt");
+        assert_eq!(puzzle.puzzle_path(), "data/2023/puzzles/07.md");
+        assert_eq!(puzzle.bin_path(), "src/bin/2023-07.rs");
+        assert_eq!(
+            puzzle.year.data_path("answers.json"),
+            "data/2023/answers.json"
+        );
+    }
+}